@@ -1,4 +1,4 @@
-use sadi::{Application, Module, Provider, Shared};
+use sadi::{Application, Export, Module, Provider, Shared};
 
 use crate::infra::persistence::sqlite::SqliteClient;
 
@@ -12,7 +12,12 @@ pub struct RootModule;
 
 impl Module for RootModule {
     fn imports(&self) -> Vec<Box<dyn Module>> {
-        vec![Box::new(RepositoriesModule), Box::new(UseCasesModule)]
+        vec![Box::new(UseCasesModule)]
+    }
+
+    // Re-export the use cases so that they resolve from the root injector.
+    fn exports(&self) -> Vec<Export> {
+        use_case_exports()
     }
 }
 
@@ -20,10 +25,8 @@ pub fn build() -> Result<Application, String> {
     let mut app = Application::new(RootModule);
 
     // Register SqliteClient first
-    app.injector().provide::<SqliteClient>(Provider::root(|_| {
-        let client = SqliteClient::new().expect("Failed to load sqlite client");
-        Shared::new(client)
-    }));
+    app.injector()
+        .provide::<SqliteClient>(Provider::try_root(|_| SqliteClient::new().map(Shared::new)));
 
     app.bootstrap();
 
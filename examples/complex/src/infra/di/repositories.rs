@@ -1,4 +1,4 @@
-use sadi::{Module, Provider, Shared};
+use sadi::{Export, Module, Provider, Shared};
 
 use crate::core::domain::todo::TodoRepository;
 use crate::core::domain::user::UserRepository;
@@ -9,14 +9,21 @@ pub struct RepositoriesModule;
 
 impl Module for RepositoriesModule {
     fn providers(&self, injector: &sadi::Injector) {
-        injector.provide::<dyn UserRepository>(Provider::root(|injector| {
+        injector.provide::<dyn UserRepository>(Provider::singleton(|injector| {
             let sqlite_client = injector.resolve::<SqliteClient>();
             Shared::new(UserSqliteRepository::new(sqlite_client)) as Shared<dyn UserRepository>
         }));
 
-        injector.provide::<dyn TodoRepository>(Provider::root(|injector| {
+        injector.provide::<dyn TodoRepository>(Provider::singleton(|injector| {
             let sqlite_client = injector.resolve::<SqliteClient>();
             Shared::new(TodoSqliteRepository::new(sqlite_client)) as Shared<dyn TodoRepository>
         }));
     }
+
+    fn exports(&self) -> Vec<Export> {
+        vec![
+            Export::of::<dyn UserRepository>(),
+            Export::of::<dyn TodoRepository>(),
+        ]
+    }
 }
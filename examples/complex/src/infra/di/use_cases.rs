@@ -1,4 +1,4 @@
-use sadi::{Injector, Module, Provider};
+use sadi::{Export, Injector, Module, Provider};
 
 use crate::core::{
     application::use_case::{
@@ -11,57 +11,84 @@ use crate::core::{
     domain::{todo::TodoRepository, user::UserRepository},
 };
 
+use super::RepositoriesModule;
+
+/// Use cases are Module singletons, like the repositories they depend on: a
+/// Root singleton would outlive them and is rejected as a captive dependency.
 pub struct UseCasesModule;
 
 impl Module for UseCasesModule {
+    fn imports(&self) -> Vec<Box<dyn Module>> {
+        vec![Box::new(RepositoriesModule)]
+    }
+
     fn providers(&self, injector: &Injector) {
         // User use cases
 
-        injector.provide::<CreateUserUseCase>(Provider::root(|injector| {
+        injector.provide::<CreateUserUseCase>(Provider::singleton(|injector| {
             let user_repository = injector.resolve::<dyn UserRepository>();
             CreateUserUseCase::new(user_repository).into()
         }));
 
-        injector.provide::<DeleteUserUseCase>(Provider::root(|injector| {
+        injector.provide::<DeleteUserUseCase>(Provider::singleton(|injector| {
             let user_repository = injector.resolve::<dyn UserRepository>();
             DeleteUserUseCase::new(user_repository).into()
         }));
 
-        injector.provide::<GetAllUserUseCase>(Provider::root(|injector| {
+        injector.provide::<GetAllUserUseCase>(Provider::singleton(|injector| {
             let user_repository = injector.resolve::<dyn UserRepository>();
             GetAllUserUseCase::new(user_repository).into()
         }));
 
-        injector.provide::<GetByIdUserUseCase>(Provider::root(|injector| {
+        injector.provide::<GetByIdUserUseCase>(Provider::singleton(|injector| {
             let user_repository = injector.resolve::<dyn UserRepository>();
             GetByIdUserUseCase::new(user_repository).into()
         }));
 
         // Todos use cases
 
-        injector.provide::<CreateTodoUseCase>(Provider::root(|injector| {
+        injector.provide::<CreateTodoUseCase>(Provider::singleton(|injector| {
             let todo_repository = injector.resolve::<dyn TodoRepository>();
             CreateTodoUseCase::new(todo_repository).into()
         }));
 
-        injector.provide::<DeleteTodoUseCase>(Provider::root(|injector| {
+        injector.provide::<DeleteTodoUseCase>(Provider::singleton(|injector| {
             let todo_repository = injector.resolve::<dyn TodoRepository>();
             DeleteTodoUseCase::new(todo_repository).into()
         }));
 
-        injector.provide::<GetAllTodoUseCase>(Provider::root(|injector| {
+        injector.provide::<GetAllTodoUseCase>(Provider::singleton(|injector| {
             let todo_repository = injector.resolve::<dyn TodoRepository>();
             GetAllTodoUseCase::new(todo_repository).into()
         }));
 
-        injector.provide::<GetByIdTodoUseCase>(Provider::root(|injector| {
+        injector.provide::<GetByIdTodoUseCase>(Provider::singleton(|injector| {
             let todo_repository = injector.resolve::<dyn TodoRepository>();
             GetByIdTodoUseCase::new(todo_repository).into()
         }));
 
-        injector.provide::<UpdateStatusTodoUseCase>(Provider::root(|injector| {
+        injector.provide::<UpdateStatusTodoUseCase>(Provider::singleton(|injector| {
             let todo_repository = injector.resolve::<dyn TodoRepository>();
             UpdateStatusTodoUseCase::new(todo_repository).into()
         }));
     }
+
+    fn exports(&self) -> Vec<Export> {
+        use_case_exports()
+    }
+}
+
+/// The use cases, published to the importer of [`UseCasesModule`].
+pub fn use_case_exports() -> Vec<Export> {
+    vec![
+        Export::of::<CreateUserUseCase>(),
+        Export::of::<DeleteUserUseCase>(),
+        Export::of::<GetAllUserUseCase>(),
+        Export::of::<GetByIdUserUseCase>(),
+        Export::of::<CreateTodoUseCase>(),
+        Export::of::<DeleteTodoUseCase>(),
+        Export::of::<GetAllTodoUseCase>(),
+        Export::of::<GetByIdTodoUseCase>(),
+        Export::of::<UpdateStatusTodoUseCase>(),
+    ]
 }
@@ -0,0 +1,90 @@
+//! Cached singleton resolution under contention.
+//!
+//! Compare the default thread-safe tables with the `lock-free` ones:
+//!
+//! ```bash
+//! cargo bench -p sadi --features thread-safe --bench contention
+//! cargo bench -p sadi --features lock-free --bench contention
+//! ```
+
+use std::sync::{Arc, Barrier};
+use std::time::{Duration, Instant};
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use sadi::{Injector, Provider, Shared};
+
+struct Config {
+    url: String,
+}
+
+struct Repository {
+    config: Shared<Config>,
+}
+
+/// A root injector with two nested children, so that every lookup from the
+/// innermost one walks the whole chain.
+fn nested_injector() -> Injector {
+    let root = Injector::root();
+    root.provide::<Config>(Provider::root(|_| {
+        Shared::new(Config {
+            url: "postgres://localhost".to_string(),
+        })
+    }));
+    root.provide::<Repository>(Provider::singleton(|injector| {
+        Shared::new(Repository {
+            config: injector.resolve::<Config>(),
+        })
+    }));
+
+    let child = Injector::child(Shared::new(root));
+    let grandchild = Injector::child(Shared::new(child));
+
+    // Warm the caches so that only hits are measured.
+    grandchild.resolve::<Repository>();
+    grandchild
+}
+
+/// Runs `iters` resolutions on each of `threads` threads started together,
+/// returning the wall-clock time of the slowest.
+fn resolve_on_threads(injector: &Arc<Injector>, threads: usize, iters: u64) -> Duration {
+    let barrier = Arc::new(Barrier::new(threads + 1));
+
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let injector = injector.clone();
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                barrier.wait();
+                for _ in 0..iters {
+                    let repository = injector.resolve::<Repository>();
+                    std::hint::black_box(repository.config.url.len());
+                }
+            })
+        })
+        .collect();
+
+    barrier.wait();
+    let start = Instant::now();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    start.elapsed()
+}
+
+fn cached_singleton(c: &mut Criterion) {
+    let injector = Arc::new(nested_injector());
+    let mut group = c.benchmark_group("cached_singleton");
+
+    for threads in [1, 4, 16] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |b, &threads| b.iter_custom(|iters| resolve_on_threads(&injector, threads, iters)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, cached_singleton);
+criterion_main!(benches);
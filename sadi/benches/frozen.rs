@@ -0,0 +1,147 @@
+//! Resolution through a bootstrapped application, before and after
+//! `Application::freeze`:
+//!
+//! ```bash
+//! cargo bench -p sadi --bench frozen
+//! cargo bench -p sadi --features thread-safe --bench frozen
+//! ```
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use sadi::{Application, Export, Injector, Module, Provider, Shared};
+
+struct Config {
+    url: String,
+}
+
+struct Database {
+    config: Shared<Config>,
+}
+
+struct Repository {
+    database: Shared<Database>,
+}
+
+struct Request {
+    repository: Shared<Repository>,
+}
+
+struct DatabaseModule;
+
+impl Module for DatabaseModule {
+    fn providers(&self, injector: &Injector) {
+        injector.provide::<Config>(Provider::root(|_| {
+            Shared::new(Config {
+                url: "postgres://localhost".to_string(),
+            })
+        }));
+        injector.provide::<Database>(Provider::singleton(|injector| {
+            Shared::new(Database {
+                config: injector.resolve::<Config>(),
+            })
+        }));
+    }
+
+    fn exports(&self) -> Vec<Export> {
+        vec![Export::of::<Database>()]
+    }
+}
+
+struct RepositoryModule;
+
+impl Module for RepositoryModule {
+    fn imports(&self) -> Vec<Box<dyn Module>> {
+        vec![Box::new(DatabaseModule)]
+    }
+
+    fn providers(&self, injector: &Injector) {
+        injector.provide::<Repository>(Provider::singleton(|injector| {
+            Shared::new(Repository {
+                database: injector.resolve::<Database>(),
+            })
+        }));
+        injector.provide::<Request>(Provider::transient(|injector| {
+            Shared::new(Request {
+                repository: injector.resolve::<Repository>(),
+            })
+        }));
+    }
+
+    fn exports(&self) -> Vec<Export> {
+        vec![Export::of::<Repository>(), Export::of::<Request>()]
+    }
+}
+
+/// Re-exports the repository module, so that its services are reached from
+/// the root injector through two levels of exports.
+struct AppModule;
+
+impl Module for AppModule {
+    fn imports(&self) -> Vec<Box<dyn Module>> {
+        vec![Box::new(RepositoryModule)]
+    }
+
+    fn exports(&self) -> Vec<Export> {
+        vec![Export::of::<Repository>(), Export::of::<Request>()]
+    }
+}
+
+fn application() -> Application {
+    let mut app = Application::new(AppModule);
+    app.bootstrap();
+
+    // Warm the caches so that singletons are only built once.
+    app.injector().resolve::<Request>();
+    app
+}
+
+fn resolve(c: &mut Criterion) {
+    // Both applications stay alive: their module injectors, which own the
+    // exported services, are dropped with them.
+    let app = application();
+    let injector = app.injector();
+    let frozen_app = application();
+    let frozen = frozen_app.freeze().unwrap();
+    let mut group = c.benchmark_group("resolve");
+
+    group.bench_function(BenchmarkId::new("injector", "root singleton"), |b| {
+        b.iter(|| injector.resolve::<Config>().url.len())
+    });
+    group.bench_function(BenchmarkId::new("frozen", "root singleton"), |b| {
+        b.iter(|| frozen.resolve::<Config>().url.len())
+    });
+
+    group.bench_function(BenchmarkId::new("injector", "exported singleton"), |b| {
+        b.iter(|| injector.resolve::<Repository>().database.config.url.len())
+    });
+    group.bench_function(BenchmarkId::new("frozen", "exported singleton"), |b| {
+        b.iter(|| frozen.resolve::<Repository>().database.config.url.len())
+    });
+
+    group.bench_function(BenchmarkId::new("injector", "transient"), |b| {
+        b.iter(|| {
+            injector
+                .resolve::<Request>()
+                .repository
+                .database
+                .config
+                .url
+                .len()
+        })
+    });
+    group.bench_function(BenchmarkId::new("frozen", "transient"), |b| {
+        b.iter(|| {
+            frozen
+                .resolve::<Request>()
+                .repository
+                .database
+                .config
+                .url
+                .len()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, resolve);
+criterion_main!(benches);
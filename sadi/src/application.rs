@@ -38,9 +38,18 @@
 //! // Use injector to resolve dependencies
 //! ```
 
-use crate::injector::Injector;
-use crate::module::Module;
-use crate::runtime::Shared;
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(not(feature = "thread-safe"))]
+use std::rc::Weak;
+#[cfg(feature = "thread-safe")]
+use std::sync::Weak;
+
+use crate::error::{BootstrapError, Error, ModuleError, ShutdownError};
+use crate::injector::{Activation, FrozenInjector, Injector};
+use crate::module::{Module, ModuleOptions};
+use crate::runtime::{Shared, Store};
 
 #[cfg(feature = "tracing")]
 use tracing::{debug, info};
@@ -92,9 +101,105 @@ pub struct Application {
     root: Option<Box<dyn Module>>,
     #[cfg(feature = "thread-safe")]
     root: Option<Box<dyn Module + Send + Sync>>,
+    root_name: &'static str,
+    /// Whether the bootstrap that took `root` failed.
+    failed: bool,
+    injector: Shared<Injector>,
+    registry: Shared<Registry>,
+}
+
+/// The modules of an application.
+///
+/// Shared with the lazy modules, which load their subtree on first use,
+/// possibly after bootstrap.
+#[derive(Default)]
+struct Registry {
+    state: Store<RegistryState>,
+}
+
+#[derive(Default)]
+struct RegistryState {
+    /// Loaded modules in load order, imports before their importer.
+    modules: Vec<LoadedModule>,
+    /// Lazy modules, including the ones loaded since.
+    lazy: Vec<LazyModule>,
+    /// Whether the `on_bootstrap` hooks of the modules loaded so far ran.
+    bootstrapped: bool,
+    /// Modules imported by more than one module, loaded under the root
+    /// injector rather than under their first importer.
+    shared: HashSet<ModuleKey>,
+}
+
+impl Registry {
+    fn update<R>(&self, f: impl FnOnce(&mut RegistryState) -> R) -> R {
+        #[cfg(not(feature = "thread-safe"))]
+        let mut state = self.state.borrow_mut();
+        #[cfg(feature = "thread-safe")]
+        let mut state = self.state.write().unwrap();
+
+        f(&mut state)
+    }
+
+    fn modules(&self) -> Vec<LoadedModule> {
+        self.update(|state| state.modules.clone())
+    }
+}
+
+/// A module kept after bootstrap, with the child injector it was loaded into.
+#[derive(Clone)]
+struct LoadedModule {
+    module: Shared<dyn Module>,
+    key: ModuleKey,
+    /// Description of the module's options, shown in errors.
+    options: Option<String>,
+    injector: Shared<Injector>,
+}
+
+/// A lazy module, registered with the activation that loads it.
+#[derive(Clone)]
+struct LazyModule {
+    module: Shared<dyn Module>,
+    key: ModuleKey,
+    injector: Shared<Injector>,
+    activation: Shared<Activation>,
+}
+
+/// A module about to be loaded into its own child injector.
+struct PendingModule {
+    module: Shared<dyn Module>,
+    key: ModuleKey,
+    options: Option<ModuleOptions>,
+    description: Option<String>,
     injector: Shared<Injector>,
 }
 
+impl PendingModule {
+    fn error(&self, error: Error) -> ModuleError {
+        ModuleError {
+            module: self.module.type_name(),
+            options: self.description.clone(),
+            error,
+        }
+    }
+}
+
+/// Identifies a module instance: its type and, for configured modules, the
+/// hash of its [`options`](Module::options).
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct ModuleKey {
+    type_id: TypeId,
+    options: Option<u64>,
+}
+
+impl ModuleKey {
+    fn of(module: &(dyn Module + 'static), options: Option<&ModuleOptions>) -> Self {
+        Self {
+            type_id: module.type_id(),
+            options: options.map(|options| options.hash),
+        }
+    }
+}
+
 #[cfg(feature = "debug")]
 impl std::fmt::Debug for Application {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -140,8 +245,11 @@ impl Application {
         info!("Creating new Application instance with root module");
 
         Self {
+            root_name: root.type_name(),
             root: Some(Box::new(root)),
+            failed: false,
             injector: Shared::new(Injector::root()),
+            registry: Shared::new(Registry::default()),
         }
     }
 
@@ -154,7 +262,9 @@ impl Application {
     ///
     /// # Panics
     ///
-    /// Panics if called more than once on the same application instance.
+    /// Panics if called more than once on the same application instance, or if
+    /// any module fails to load. Use [`try_bootstrap()`](Application::try_bootstrap)
+    /// to handle these errors instead.
     ///
     /// # Examples
     ///
@@ -193,15 +303,110 @@ impl Application {
     /// app.bootstrap(); // Panics: Application already bootstrapped
     /// ```
     pub fn bootstrap(&mut self) {
-        let root = self.root.take().expect("Application already bootstrapped");
+        if let Err(error) = self.try_bootstrap() {
+            panic!("{}", error);
+        }
+    }
+
+    /// Bootstraps the application, returning every failure instead of panicking.
+    ///
+    /// Modules are loaded through [`Module::try_providers`], followed by
+    /// [`Module::on_init`]. Once the whole import tree is loaded without errors,
+    /// [eager](crate::provider::Provider::eager) providers are instantiated, then
+    /// [`Module::on_bootstrap`] runs for every module in load order. When a module
+    /// fails, loading continues with the rest of the import tree, and the
+    /// returned [`BootstrapError`] lists each failure tagged with the offending
+    /// module's [`type_name()`](Module::type_name). Calling this method on an
+    /// application that was already bootstrapped fails with
+    /// [`ErrorKind::AlreadyBootstrapped`](crate::error::ErrorKind::AlreadyBootstrapped).
+    ///
+    /// A bootstrap is attempted only once. After an `Err`, the application is
+    /// not [bootstrapped](Application::is_bootstrapped) and calling this method
+    /// again fails with `AlreadyBootstrapped` as well: the injector keeps the
+    /// providers registered before the failure, and the singletons already
+    /// built can still be released with [`shutdown`](Application::shutdown).
+    /// To retry, create a new application.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Error, ErrorKind, Injector, Module, Provider, Shared};
+    ///
+    /// struct ConfigModule;
+    ///
+    /// impl Module for ConfigModule {
+    ///     fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+    ///         injector.try_provide::<String>(Provider::root(|_| Shared::new("a".to_string())))?;
+    ///         injector.try_provide::<String>(Provider::root(|_| Shared::new("b".to_string())))?;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(ConfigModule);
+    /// let err = app.try_bootstrap().unwrap_err();
+    ///
+    /// assert_eq!(err.errors.len(), 1);
+    /// assert!(err.errors[0].module.contains("ConfigModule"));
+    /// assert!(err.errors[0].error.kind == ErrorKind::ProviderAlreadyRegistered);
+    /// ```
+    pub fn try_bootstrap(&mut self) -> Result<(), BootstrapError> {
+        let Some(root) = self.root.take() else {
+            let error = if self.failed {
+                Error::bootstrap_already_failed()
+            } else {
+                Error::already_bootstrapped()
+            };
+            return Err(BootstrapError {
+                errors: vec![ModuleError::new(self.root_name, error)],
+            });
+        };
 
         #[cfg(feature = "tracing")]
         info!("Starting application bootstrap process");
 
-        Self::load_module(self.injector.clone(), root);
+        let shared = Self::shared_modules(root.as_ref());
+        self.registry.update(|state| state.shared = shared);
+
+        let mut errors = Vec::new();
+        Self::load_module(
+            &self.injector,
+            root,
+            &self.registry,
+            &mut Vec::new(),
+            &mut errors,
+        );
+
+        if errors.is_empty() {
+            let mut failures = Vec::new();
+            self.injector.instantiate_eager_into(&mut failures);
+            errors.extend(
+                failures
+                    .into_iter()
+                    .map(|error| ModuleError::new(self.root_name, error)),
+            );
+
+            Self::instantiate_eager(&self.registry, 0, &mut errors);
+        }
+
+        if errors.is_empty() {
+            Self::run_bootstrap_hooks(&self.registry, 0, &mut errors);
+        }
+
+        if !errors.is_empty() {
+            #[cfg(feature = "tracing")]
+            info!(
+                "Application bootstrap failed with {} error(s)",
+                errors.len()
+            );
+
+            self.failed = true;
+            return Err(BootstrapError { errors });
+        }
 
         #[cfg(feature = "tracing")]
         info!("Application bootstrap completed successfully");
+
+        Ok(())
     }
 
     /// Returns a shared reference to the root injector.
@@ -251,13 +456,14 @@ impl Application {
 
     /// Checks whether the application has been bootstrapped.
     ///
-    /// Returns `true` if [`bootstrap()`](Application::bootstrap) has been called,
-    /// `false` otherwise.
+    /// Returns `true` if [`bootstrap()`](Application::bootstrap) has completed
+    /// successfully, `false` otherwise.
     ///
     /// # Returns
     ///
     /// - `true` if the application is bootstrapped
-    /// - `false` if the application has not been bootstrapped yet
+    /// - `false` if the application has not been bootstrapped yet, or its
+    ///   bootstrap failed
     ///
     /// # Examples
     ///
@@ -278,7 +484,7 @@ impl Application {
     /// assert!(app.is_bootstrapped());
     /// ```
     pub fn is_bootstrapped(&self) -> bool {
-        let bootstrapped = self.root.is_none();
+        let bootstrapped = self.root.is_none() && !self.failed;
 
         #[cfg(feature = "tracing")]
         debug!("Checking application bootstrap state: {}", bootstrapped);
@@ -286,6 +492,144 @@ impl Application {
         bootstrapped
     }
 
+    /// Freezes the application's injectors for resolution only.
+    ///
+    /// Lazy modules are loaded first: a frozen injector accepts no provider,
+    /// so they could not load on first use afterwards. Freezing therefore
+    /// gives up [lazy loading](Module::is_lazy), running every lazy module's
+    /// imports, providers and `on_init` hook right away; applications that
+    /// rely on lazy modules to keep startup short should not freeze. Then the
+    /// root injector and every module injector record, for each type they can
+    /// resolve, the injector owning its provider, so that resolutions,
+    /// including the ones made by factories, no longer walk parent injectors
+    /// and module exports.
+    /// Registering a provider in a frozen injector fails with
+    /// [`ErrorKind::Frozen`](crate::error::ErrorKind::Frozen).
+    ///
+    /// Call it after [`bootstrap()`](Application::bootstrap); freezing again
+    /// keeps the existing indexes. Fails with the error of a lazy module that
+    /// does not load.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, ErrorKind, Injector, Module, Provider, Shared};
+    ///
+    /// struct Config {
+    ///     port: u16,
+    /// }
+    ///
+    /// struct AppModule;
+    ///
+    /// impl Module for AppModule {
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Config>(Provider::root(|_| Shared::new(Config { port: 8080 })));
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(AppModule);
+    /// app.bootstrap();
+    ///
+    /// let frozen = app.freeze().unwrap();
+    /// assert_eq!(frozen.resolve::<Config>().port, 8080);
+    ///
+    /// let err = app
+    ///     .injector()
+    ///     .try_provide::<u32>(Provider::root(|_| Shared::new(1)))
+    ///     .err()
+    ///     .unwrap();
+    /// assert!(err.kind == ErrorKind::Frozen);
+    /// ```
+    pub fn freeze(&self) -> Result<FrozenInjector, Error> {
+        #[cfg(feature = "tracing")]
+        info!("Freezing application");
+
+        // Activating a lazy module may register the lazy modules it imports.
+        let mut index = 0;
+        while let Some(lazy) = self.registry.update(|state| state.lazy.get(index).cloned()) {
+            lazy.activation.activate(&self.injector)?;
+            index += 1;
+        }
+
+        self.injector.freeze();
+        for loaded in self.registry.modules() {
+            loaded.injector.freeze();
+        }
+
+        Ok(FrozenInjector::new(self.injector.clone()))
+    }
+
+    /// Disposes every singleton created by the application.
+    ///
+    /// Modules are shut down first, in reverse load order: each module's
+    /// [`Module::on_shutdown`] hook runs, then its injector is disposed. The
+    /// root injector holding the Root-scoped singletons is disposed last. Within each injector,
+    /// instances are disposed in reverse creation order (see
+    /// [`Injector::dispose`]). Every hook runs even when some fail, and all
+    /// failures are returned together. Calling it again only disposes the
+    /// singletons created since the previous call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Dispose, DisposeError, Injector, Module, Provider, Shared};
+    ///
+    /// struct Pool;
+    ///
+    /// impl Dispose for Pool {
+    ///     fn dispose(&self) -> Result<(), DisposeError> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// struct AppModule;
+    ///
+    /// impl Module for AppModule {
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Pool>(Provider::root(|_| Shared::new(Pool)).with_dispose());
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(AppModule);
+    /// app.bootstrap();
+    /// app.injector().resolve::<Pool>();
+    ///
+    /// assert!(app.shutdown().is_ok());
+    /// ```
+    pub fn shutdown(&self) -> Result<(), ShutdownError> {
+        #[cfg(feature = "tracing")]
+        info!("Shutting down application");
+
+        let mut errors = Vec::new();
+
+        for loaded in self.registry.modules().iter().rev() {
+            loaded.shutdown_hook(&mut errors);
+            loaded.injector.dispose_into(&mut errors);
+        }
+        self.injector.dispose_into(&mut errors);
+
+        ShutdownError::check(errors)
+    }
+
+    /// Disposes every singleton created by the application, awaiting async
+    /// dispose hooks.
+    ///
+    /// The order is the same as for [`shutdown()`](Application::shutdown).
+    pub async fn shutdown_async(&self) -> Result<(), ShutdownError> {
+        #[cfg(feature = "tracing")]
+        info!("Shutting down application");
+
+        let mut errors = Vec::new();
+
+        for loaded in self.registry.modules().iter().rev() {
+            loaded.shutdown_hook(&mut errors);
+            loaded.injector.dispose_async_into(&mut errors).await;
+        }
+        self.injector.dispose_async_into(&mut errors).await;
+
+        ShutdownError::check(errors)
+    }
+
     /// Recursively loads a module and its imports into the injector hierarchy.
     ///
     /// Creates a child injector for the module, loads all imported modules first,
@@ -296,11 +640,119 @@ impl Application {
     ///
     /// - `parent`: The parent injector to create a child from
     /// - `module`: The module to load
-    fn load_module(parent: Shared<Injector>, module: Box<dyn Module>) {
+    /// - `registry`: Collects every loaded module with its injector, in load order
+    /// - `loading`: The modules whose imports are being loaded, used to detect cycles
+    /// - `errors`: Collects the failures of this module and its imports
+    ///
+    /// A module that is already loaded is not loaded again: the existing
+    /// instance is shared and its exports are published to the new importer.
+    /// Modules of the same type count as the same module unless their
+    /// [`options`](Module::options) differ. A [lazy](Module::is_lazy) module
+    /// only publishes its exports here, and is loaded by the first resolution
+    /// of one of them.
+    fn load_module(
+        parent: &Shared<Injector>,
+        module: Box<dyn Module>,
+        registry: &Shared<Registry>,
+        loading: &mut Vec<(ModuleKey, &'static str)>,
+        errors: &mut Vec<ModuleError>,
+    ) {
         #[cfg(feature = "tracing")]
         debug!("Loading module into injector hierarchy");
 
-        let module_injector = Shared::new(Injector::child(parent.clone()));
+        let module: Shared<dyn Module> = Shared::from(module);
+        let options = module.options();
+        let key = ModuleKey::of(module.as_ref(), options.as_ref());
+
+        // A module with several importers must not see the private providers
+        // of whichever one loads it first.
+        let injector = if registry.update(|state| state.shared.contains(&key)) {
+            Injector::child(Shared::new(parent.root_injector()))
+        } else {
+            Injector::child(parent.clone())
+        };
+
+        let pending = PendingModule {
+            key,
+            description: options
+                .as_ref()
+                .map(|options| options.description().to_string()),
+            options,
+            injector: Shared::new(injector),
+            module,
+        };
+
+        if let Some(start) = loading.iter().position(|(loading, _)| *loading == key) {
+            let mut chain: Vec<&str> = loading[start..].iter().map(|(_, name)| *name).collect();
+            chain.push(pending.module.type_name());
+
+            errors.push(pending.error(Error::circular_import(&chain)));
+            return;
+        }
+
+        let (loaded, lazy) = registry.update(|state| {
+            (
+                state
+                    .modules
+                    .iter()
+                    .find(|loaded| loaded.key == key)
+                    .cloned(),
+                state.lazy.iter().find(|lazy| lazy.key == key).cloned(),
+            )
+        });
+
+        if loaded.is_some() || lazy.is_some() {
+            #[cfg(feature = "tracing")]
+            debug!(
+                "Module {} already loaded, sharing it",
+                pending.module.type_name()
+            );
+
+            let exported = match (loaded, lazy) {
+                (Some(loaded), _) => {
+                    Self::export_module(loaded.module.as_ref(), &loaded.injector, parent)
+                }
+                (None, Some(lazy)) => Self::export_lazy_module(
+                    lazy.module.as_ref(),
+                    &lazy.injector,
+                    parent,
+                    &lazy.activation,
+                ),
+                (None, None) => Ok(()),
+            };
+            if let Err(error) = exported {
+                errors.push(pending.error(error));
+            }
+            return;
+        }
+
+        if pending.module.is_lazy() {
+            #[cfg(feature = "tracing")]
+            debug!(
+                "Module {} is lazy, deferring its loading",
+                pending.module.type_name()
+            );
+
+            Self::register_lazy(parent, pending, registry, errors);
+            return;
+        }
+
+        Self::load_pending(Some(parent), pending, registry, loading, errors);
+    }
+
+    /// Loads the imports and providers of a module whose injector exists.
+    ///
+    /// `parent` receives the module's exports; it is `None` for lazy modules,
+    /// whose exports were published when they were registered.
+    fn load_pending(
+        parent: Option<&Shared<Injector>>,
+        pending: PendingModule,
+        registry: &Shared<Registry>,
+        loading: &mut Vec<(ModuleKey, &'static str)>,
+        errors: &mut Vec<ModuleError>,
+    ) {
+        let module = pending.module.clone();
+        let module_injector = pending.injector.clone();
 
         #[cfg(feature = "tracing")]
         debug!("Created child injector for module");
@@ -311,27 +763,267 @@ impl Application {
             debug!("Module has {} imports, loading them first", imports.len());
         }
 
+        loading.push((pending.key, module.type_name()));
+
         #[allow(unused_variables)]
         for (index, import) in imports.into_iter().enumerate() {
             #[cfg(feature = "tracing")]
             debug!("Loading import {}", index + 1);
 
-            Self::load_module(module_injector.clone(), import);
+            Self::load_module(&module_injector, import, registry, loading, errors);
         }
 
+        loading.pop();
+
         #[cfg(feature = "tracing")]
         debug!("Registering module providers");
 
-        module.providers(&module_injector);
+        let registered = match &pending.options {
+            Some(options) => options.register(&module_injector),
+            None => Ok(()),
+        };
+
+        let loaded = registered
+            .and_then(|()| module.try_providers(&module_injector))
+            .map(|()| module_injector.publish_contributions())
+            .and_then(|()| match parent {
+                Some(parent) => Self::export_module(module.as_ref(), &module_injector, parent),
+                None => Ok(()),
+            })
+            .and_then(|()| {
+                if module.is_global() {
+                    module_injector.export_all_to_root()
+                } else {
+                    Ok(())
+                }
+            })
+            .and_then(|()| module.on_init(&module_injector));
+
+        match loaded {
+            Ok(()) => {
+                #[cfg(feature = "tracing")]
+                debug!("Module loaded successfully");
+            }
+            Err(error) => {
+                #[cfg(feature = "tracing")]
+                debug!("Module {} failed to load", module.type_name());
+
+                errors.push(pending.error(error));
+            }
+        }
+
+        registry.update(|state| {
+            state.modules.push(LoadedModule {
+                module,
+                key: pending.key,
+                options: pending.description,
+                injector: module_injector,
+            })
+        });
+    }
+
+    /// Finds the modules imported by more than one module below `root`,
+    /// lazy ones included.
+    fn shared_modules(root: &(dyn Module + 'static)) -> HashSet<ModuleKey> {
+        let root_key = ModuleKey::of(root, root.options().as_ref());
+        let mut importers: HashMap<ModuleKey, HashSet<ModuleKey>> = HashMap::new();
+        let mut scanned = HashSet::from([root_key]);
+        let mut imports: Vec<_> = root
+            .imports()
+            .into_iter()
+            .map(|import| (root_key, import))
+            .collect();
+
+        while let Some((importer, module)) = imports.pop() {
+            let key = ModuleKey::of(module.as_ref(), module.options().as_ref());
+            importers.entry(key).or_default().insert(importer);
+
+            if scanned.insert(key) {
+                imports.extend(module.imports().into_iter().map(|import| (key, import)));
+            }
+        }
+
+        importers
+            .into_iter()
+            .filter(|(_, importers)| importers.len() > 1)
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Publishes the exports of a lazy module, to be loaded on first use.
+    fn register_lazy(
+        parent: &Shared<Injector>,
+        pending: PendingModule,
+        registry: &Shared<Registry>,
+        errors: &mut Vec<ModuleError>,
+    ) {
+        let module = pending.module.clone();
+        let module_injector = pending.injector.clone();
+        let key = pending.key;
+        let description = pending.description.clone();
+
+        let weak_registry: Weak<Registry> = Shared::downgrade(registry);
+        let activation = Shared::new(Activation::new(
+            module.type_name(),
+            Box::new(move || {
+                let Some(registry) = weak_registry.upgrade() else {
+                    return Err(Error::lazy_module_failed(pending.module.type_name(), &[]));
+                };
+
+                #[cfg(feature = "tracing")]
+                debug!("Activating lazy module {}", pending.module.type_name());
+
+                let module_name = pending.module.type_name();
+                let exports = pending.module.exports();
+                let lazy_injector = pending.injector.clone();
+                let options = pending.description.clone();
+                let first = registry.update(|state| state.modules.len());
+                let mut errors = Vec::new();
+
+                Self::load_pending(None, pending, &registry, &mut Vec::new(), &mut errors);
+
+                // The exports were published before the module registered
+                // anything; each must now lead to a provider.
+                if errors.is_empty() {
+                    for export in &exports {
+                        if let Err(error) = lazy_injector.check_export(&export.key) {
+                            errors.push(ModuleError {
+                                module: module_name,
+                                options: options.clone(),
+                                error,
+                            });
+                        }
+                    }
+                }
+
+                if errors.is_empty() {
+                    Self::instantiate_eager(&registry, first, &mut errors);
+                }
+
+                if errors.is_empty() && registry.update(|state| state.bootstrapped) {
+                    Self::run_bootstrap_hooks(&registry, first, &mut errors);
+                }
+
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(Error::lazy_module_failed(module_name, &errors))
+                }
+            }),
+        ));
+
+        let mut exported =
+            Self::export_lazy_module(module.as_ref(), &module_injector, parent, &activation);
+        if exported.is_ok() && module.is_global() {
+            exported = Self::export_lazy_module(
+                module.as_ref(),
+                &module_injector,
+                &module_injector.root_injector(),
+                &activation,
+            );
+        }
+
+        if let Err(error) = exported {
+            errors.push(ModuleError {
+                module: module.type_name(),
+                options: description,
+                error,
+            });
+        }
+
+        registry.update(|state| {
+            state.lazy.push(LazyModule {
+                module,
+                key,
+                injector: module_injector,
+                activation,
+            })
+        });
+    }
+
+    /// Instantiates the [eager](crate::provider::Provider::eager) providers of
+    /// the modules loaded from index `from` on, in load order.
+    fn instantiate_eager(registry: &Registry, from: usize, errors: &mut Vec<ModuleError>) {
+        let mut index = from;
+
+        while let Some(loaded) = registry.update(|state| state.modules.get(index).cloned()) {
+            let mut failures = Vec::new();
+            loaded.injector.instantiate_eager_into(&mut failures);
+            errors.extend(failures.into_iter().map(|error| loaded.error(error)));
+            index += 1;
+        }
+    }
+
+    /// Runs [`Module::on_bootstrap`] for the modules loaded from index `from`
+    /// on, including the ones loaded while the hooks run.
+    fn run_bootstrap_hooks(registry: &Registry, from: usize, errors: &mut Vec<ModuleError>) {
+        let mut index = from;
+
+        while let Some(loaded) = registry.update(|state| state.modules.get(index).cloned()) {
+            #[cfg(feature = "tracing")]
+            debug!("Running on_bootstrap for {}", loaded.module.type_name());
+
+            if let Err(error) = loaded.module.on_bootstrap(&loaded.injector) {
+                errors.push(loaded.error(error));
+            }
+            index += 1;
+        }
+
+        registry.update(|state| state.bootstrapped = true);
+    }
+
+    /// Publishes the module's [`exports`](Module::exports) to its importer.
+    fn export_module(
+        module: &dyn Module,
+        module_injector: &Injector,
+        parent: &Injector,
+    ) -> Result<(), Error> {
+        for export in module.exports() {
+            module_injector.export_to(parent, &export.key)?;
+        }
+        Ok(())
+    }
+
+    /// Publishes the exports of a lazy module, activating it on first use.
+    fn export_lazy_module(
+        module: &dyn Module,
+        module_injector: &Injector,
+        parent: &Injector,
+        activation: &Shared<Activation>,
+    ) -> Result<(), Error> {
+        for export in module.exports() {
+            module_injector.export_lazy_to(parent, &export.key, activation)?;
+        }
+        Ok(())
+    }
+}
+
+impl LoadedModule {
+    fn error(&self, error: Error) -> ModuleError {
+        ModuleError {
+            module: self.module.type_name(),
+            options: self.options.clone(),
+            error,
+        }
+    }
 
+    fn shutdown_hook(&self, errors: &mut Vec<Error>) {
         #[cfg(feature = "tracing")]
-        debug!("Module loaded successfully");
+        debug!("Running on_shutdown for {}", self.module.type_name());
+
+        if let Err(error) = self.module.on_shutdown(&self.injector) {
+            errors.push(error);
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ErrorKind;
+    use crate::injector::Key;
+    use crate::provider::Provider;
+    use crate::scope::{CaptivePolicy, Scope};
 
     #[cfg(not(feature = "thread-safe"))]
     use std::cell::RefCell;
@@ -512,11 +1204,19 @@ mod tests {
         let mut app = Application::new(module);
         app.bootstrap();
 
-        // 2 imports + 1 root module = 3 calls
+        // The duplicated import is loaded once: 1 import + 1 root module = 2 calls
         #[cfg(not(feature = "thread-safe"))]
-        assert_eq!(*counter.borrow(), 3, "All modules should be loaded");
+        assert_eq!(
+            *counter.borrow(),
+            2,
+            "Each module type should be loaded once"
+        );
         #[cfg(feature = "thread-safe")]
-        assert_eq!(*counter.lock().unwrap(), 3, "All modules should be loaded");
+        assert_eq!(
+            *counter.lock().unwrap(),
+            2,
+            "Each module type should be loaded once"
+        );
     }
 
     #[test]
@@ -568,50 +1268,30 @@ mod tests {
         );
     }
 
-    // NestedImportModule with conditional thread safety
-    #[cfg(not(feature = "thread-safe"))]
-    struct NestedImportModule {
-        counter: Rc<RefCell<usize>>,
-        depth: usize,
+    // Every level is its own type, since modules are deduplicated by type.
+    struct NestedImportModule<const DEPTH: usize> {
+        counter: Shared<std::sync::Mutex<usize>>,
     }
 
-    #[cfg(not(feature = "thread-safe"))]
-    impl Module for NestedImportModule {
-        fn imports(&self) -> Vec<Box<dyn Module>> {
-            if self.depth > 0 {
-                vec![Box::new(NestedImportModule {
-                    counter: self.counter.clone(),
-                    depth: self.depth - 1,
-                })]
-            } else {
-                vec![]
+    macro_rules! nested_import_module {
+        ($($depth:literal => $import:literal),*) => {$(
+            impl Module for NestedImportModule<$depth> {
+                fn imports(&self) -> Vec<Box<dyn Module>> {
+                    vec![Box::new(NestedImportModule::<$import> {
+                        counter: self.counter.clone(),
+                    })]
+                }
+
+                fn providers(&self, _injector: &Injector) {
+                    *self.counter.lock().unwrap() += 1;
+                }
             }
-        }
-
-        fn providers(&self, _injector: &Injector) {
-            *self.counter.borrow_mut() += 1;
-        }
-    }
-
-    #[cfg(feature = "thread-safe")]
-    struct NestedImportModule {
-        counter: Arc<Mutex<usize>>,
-        depth: usize,
+        )*};
     }
 
-    #[cfg(feature = "thread-safe")]
-    impl Module for NestedImportModule {
-        fn imports(&self) -> Vec<Box<dyn Module>> {
-            if self.depth > 0 {
-                vec![Box::new(NestedImportModule {
-                    counter: self.counter.clone(),
-                    depth: self.depth - 1,
-                })]
-            } else {
-                vec![]
-            }
-        }
+    nested_import_module!(5 => 4, 4 => 3, 3 => 2, 2 => 1, 1 => 0);
 
+    impl Module for NestedImportModule<0> {
         fn providers(&self, _injector: &Injector) {
             *self.counter.lock().unwrap() += 1;
         }
@@ -619,27 +1299,1528 @@ mod tests {
 
     #[test]
     fn test_deeply_nested_modules() {
-        #[cfg(not(feature = "thread-safe"))]
-        let counter = Rc::new(RefCell::new(0));
-        #[cfg(feature = "thread-safe")]
-        let counter = Arc::new(Mutex::new(0));
+        let counter = Shared::new(std::sync::Mutex::new(0));
 
-        let module = NestedImportModule {
+        let module = NestedImportModule::<5> {
             counter: counter.clone(),
-            depth: 5,
         };
 
         let mut app = Application::new(module);
         app.bootstrap();
 
         // depth 5, 4, 3, 2, 1, 0 = 6 modules total
-        #[cfg(not(feature = "thread-safe"))]
-        assert_eq!(*counter.borrow(), 6, "All nested modules should be loaded");
-        #[cfg(feature = "thread-safe")]
         assert_eq!(
             *counter.lock().unwrap(),
             6,
             "All nested modules should be loaded"
         );
     }
+
+    trait Handler: Send + Sync {
+        fn handle(&self) -> &'static str;
+    }
+
+    struct StaticHandler(&'static str);
+
+    impl Handler for StaticHandler {
+        fn handle(&self) -> &'static str {
+            self.0
+        }
+    }
+
+    struct HandlerModule<const ID: usize> {
+        command: &'static str,
+        reply: &'static str,
+    }
+
+    impl<const ID: usize> Module for HandlerModule<ID> {
+        fn providers(&self, injector: &Injector) {
+            let reply = self.reply;
+            injector.provide_map_entry::<&str, dyn Handler>(
+                self.command,
+                Provider::root(move |_| Shared::new(StaticHandler(reply)) as Shared<dyn Handler>),
+            );
+        }
+    }
+
+    struct DispatcherModule {
+        commands: [(&'static str, &'static str); 2],
+    }
+
+    impl Module for DispatcherModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            let [(first, first_reply), (second, second_reply)] = self.commands;
+            vec![
+                Box::new(HandlerModule::<1> {
+                    command: first,
+                    reply: first_reply,
+                }),
+                Box::new(HandlerModule::<2> {
+                    command: second,
+                    reply: second_reply,
+                }),
+            ]
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+    }
+
+    #[test]
+    fn test_modules_contribute_map_entries() {
+        let mut app = Application::new(DispatcherModule {
+            commands: [("create", "created"), ("delete", "deleted")],
+        });
+        app.bootstrap();
+
+        let handlers = app.injector().resolve_map::<&str, dyn Handler>();
+        assert_eq!(handlers.len(), 2);
+        assert_eq!(handlers["create"].handle(), "created");
+        assert_eq!(handlers["delete"].handle(), "deleted");
+    }
+
+    #[test]
+    #[should_panic(expected = "contributed more than once")]
+    fn test_modules_contributing_same_map_key_fail() {
+        let mut app = Application::new(DispatcherModule {
+            commands: [("create", "created"), ("create", "created again")],
+        });
+        app.bootstrap();
+    }
+
+    struct PortModule;
+
+    impl Module for PortModule {
+        fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+            injector.try_provide::<u16>(Provider::root(|_| Shared::new(8080)))
+        }
+    }
+
+    struct HostModule;
+
+    impl Module for HostModule {
+        fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+            injector.try_provide::<String>(Provider::root(|_| Shared::new("a".to_string())))?;
+            injector.try_provide::<String>(Provider::root(|_| Shared::new("b".to_string())))
+        }
+    }
+
+    struct ServerModule;
+
+    impl Module for ServerModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![
+                Box::new(PortModule),
+                Box::new(HostModule),
+                Box::new(EmptyModule),
+                Box::new(PortModule),
+            ]
+        }
+
+        fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+            injector.try_provide::<u16>(Provider::root(|_| Shared::new(9090)))
+        }
+    }
+
+    #[test]
+    fn test_try_bootstrap_succeeds() {
+        let mut app = Application::new(PortModule);
+
+        assert!(app.try_bootstrap().is_ok());
+        assert!(app.is_bootstrapped());
+        assert_eq!(*app.injector().resolve::<u16>(), 8080);
+    }
+
+    #[test]
+    fn test_try_bootstrap_collects_errors_across_import_tree() {
+        let mut app = Application::new(ServerModule);
+
+        let err = app.try_bootstrap().unwrap_err();
+        let modules: Vec<_> = err.errors.iter().map(|error| error.module).collect();
+
+        assert_eq!(
+            modules,
+            [
+                std::any::type_name::<HostModule>(),
+                std::any::type_name::<ServerModule>(),
+            ]
+        );
+        assert!(
+            err.errors
+                .iter()
+                .all(|error| error.error.kind == ErrorKind::ProviderAlreadyRegistered)
+        );
+        assert_eq!(*app.injector().resolve::<u16>(), 8080);
+    }
+
+    #[test]
+    fn test_try_bootstrap_twice_returns_error() {
+        let mut app = Application::new(EmptyModule);
+        app.try_bootstrap().unwrap();
+
+        let err = app.try_bootstrap().unwrap_err();
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].module, std::any::type_name::<EmptyModule>());
+        assert!(err.errors[0].error.kind == ErrorKind::AlreadyBootstrapped);
+    }
+
+    #[test]
+    fn test_failed_bootstrap_is_not_bootstrapped_and_cannot_be_retried() {
+        let mut app = Application::new(HostModule);
+        app.try_bootstrap().unwrap_err();
+        assert!(!app.is_bootstrapped());
+
+        let err = app.try_bootstrap().unwrap_err();
+        assert_eq!(err.errors.len(), 1);
+        assert!(err.errors[0].error.kind == ErrorKind::AlreadyBootstrapped);
+        assert!(err.errors[0].error.message.contains("failed"));
+        assert!(!app.is_bootstrapped());
+    }
+
+    type DisposeLog = Shared<std::sync::Mutex<Vec<&'static str>>>;
+
+    struct Closable {
+        name: &'static str,
+        log: DisposeLog,
+    }
+
+    impl crate::dispose::Dispose for Closable {
+        fn dispose(&self) -> Result<(), crate::dispose::DisposeError> {
+            self.log.lock().unwrap().push(self.name);
+            Ok(())
+        }
+    }
+
+    fn closable(log: &DisposeLog, name: &'static str, scope: Scope) -> Provider<Closable> {
+        let log = log.clone();
+        let factory = move |_: &Injector| {
+            Shared::new(Closable {
+                name,
+                log: log.clone(),
+            })
+        };
+
+        match scope {
+            Scope::Root => Provider::root(factory),
+            _ => Provider::singleton(factory),
+        }
+        .with_dispose()
+    }
+
+    struct ClosingModule<const ID: usize> {
+        name: &'static str,
+        log: DisposeLog,
+    }
+
+    impl<const ID: usize> Module for ClosingModule<ID> {
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<Closable>(closable(&self.log, self.name, Scope::Module));
+            injector.resolve::<Closable>();
+        }
+    }
+
+    struct ClosingAppModule {
+        log: DisposeLog,
+    }
+
+    impl Module for ClosingAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![
+                Box::new(ClosingModule::<1> {
+                    name: "a",
+                    log: self.log.clone(),
+                }),
+                Box::new(ClosingModule::<2> {
+                    name: "b",
+                    log: self.log.clone(),
+                }),
+            ]
+        }
+
+        fn providers(&self, injector: &Injector) {
+            injector.provide_named::<Closable>("root", closable(&self.log, "root", Scope::Root));
+            injector.provide::<Closable>(closable(&self.log, "app", Scope::Module));
+            injector.resolve_named::<Closable>("root");
+            injector.resolve::<Closable>();
+        }
+    }
+
+    #[test]
+    fn test_shutdown_disposes_modules_before_root() {
+        let log = DisposeLog::default();
+        let mut app = Application::new(ClosingAppModule { log: log.clone() });
+        app.bootstrap();
+
+        app.shutdown().unwrap();
+        assert_eq!(*log.lock().unwrap(), ["app", "b", "a", "root"]);
+
+        app.shutdown().unwrap();
+        assert_eq!(log.lock().unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_async_uses_same_order() {
+        let log = DisposeLog::default();
+        let mut app = Application::new(ClosingAppModule { log: log.clone() });
+        app.bootstrap();
+
+        app.shutdown_async().await.unwrap();
+        assert_eq!(*log.lock().unwrap(), ["app", "b", "a", "root"]);
+    }
+
+    type EventLog = Shared<std::sync::Mutex<Vec<String>>>;
+
+    struct Greeting(&'static str);
+
+    struct HookModule<const ID: usize> {
+        name: &'static str,
+        imports: Vec<&'static str>,
+        log: EventLog,
+    }
+
+    impl<const ID: usize> HookModule<ID> {
+        fn record(&self, event: &str) {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{}:{}", event, self.name));
+        }
+    }
+
+    fn hook_import<const ID: usize>(name: &'static str, log: &EventLog) -> Box<dyn Module> {
+        Box::new(HookModule::<ID> {
+            name,
+            imports: vec![],
+            log: log.clone(),
+        })
+    }
+
+    impl<const ID: usize> Module for HookModule<ID> {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            self.imports
+                .iter()
+                .enumerate()
+                .map(|(index, &name)| match index {
+                    0 => hook_import::<1>(name, &self.log),
+                    _ => hook_import::<2>(name, &self.log),
+                })
+                .collect()
+        }
+
+        fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+            let name = self.name;
+            injector.try_provide_named::<Greeting>(
+                name,
+                Provider::root(move |_| Shared::new(Greeting(name))),
+            )?;
+            injector
+                .try_provide::<Greeting>(Provider::singleton(move |_| Shared::new(Greeting(name))))
+        }
+
+        fn on_init(&self, injector: &Injector) -> Result<(), Error> {
+            assert_eq!(injector.try_resolve::<Greeting>()?.0, self.name);
+            if self.name == "broken" {
+                return Err(Error::service_not_provided("Broken"));
+            }
+            self.record("init");
+            Ok(())
+        }
+
+        fn on_bootstrap(&self, injector: &Injector) -> Result<(), Error> {
+            for &name in ["a", "b"].iter().filter(|&&name| name != self.name) {
+                injector.try_resolve_named::<Greeting>(name)?;
+            }
+            self.record("bootstrap");
+            Ok(())
+        }
+
+        fn on_shutdown(&self, _injector: &Injector) -> Result<(), Error> {
+            self.record("shutdown");
+            if self.name == "a" {
+                return Err(Error::service_not_provided("Socket"));
+            }
+            Ok(())
+        }
+    }
+
+    fn hook_app(log: &EventLog, imports: Vec<&'static str>) -> Application {
+        Application::new(HookModule::<0> {
+            name: "app",
+            imports,
+            log: log.clone(),
+        })
+    }
+
+    #[test]
+    fn test_lifecycle_hooks_run_in_dependency_order() {
+        let log = EventLog::default();
+        let mut app = hook_app(&log, vec!["a", "b"]);
+        app.try_bootstrap().unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "init:a",
+                "init:b",
+                "init:app",
+                "bootstrap:a",
+                "bootstrap:b",
+                "bootstrap:app",
+            ]
+        );
+
+        log.lock().unwrap().clear();
+        let err = app.shutdown().unwrap_err();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["shutdown:app", "shutdown:b", "shutdown:a"]
+        );
+        assert_eq!(err.errors.len(), 1);
+        assert!(err.errors[0].message.contains("Socket"));
+    }
+
+    #[test]
+    fn test_failed_init_skips_bootstrap_hooks() {
+        let log = EventLog::default();
+        let mut app = hook_app(&log, vec!["a", "broken"]);
+
+        let err = app.try_bootstrap().unwrap_err();
+
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].module, std::any::type_name::<HookModule<2>>());
+        assert_eq!(*log.lock().unwrap(), ["init:a", "init:app"]);
+    }
+
+    #[derive(Debug)]
+    struct Ledger;
+    struct Secret;
+
+    struct Consumer {
+        ledger: Shared<Ledger>,
+    }
+
+    struct StoreModule;
+
+    impl Module for StoreModule {
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<Ledger>(Provider::singleton(|_| Shared::new(Ledger)));
+            injector.provide::<Secret>(Provider::singleton(|_| Shared::new(Secret)));
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Ledger>()]
+        }
+    }
+
+    struct ConsumerModule;
+
+    impl Module for ConsumerModule {
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<Consumer>(Provider::singleton(|injector| {
+                Shared::new(Consumer {
+                    ledger: injector.resolve::<Ledger>(),
+                })
+            }));
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Consumer>()]
+        }
+    }
+
+    struct ExportingAppModule {
+        reexport: bool,
+    }
+
+    impl Module for ExportingAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(StoreModule), Box::new(ConsumerModule)]
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            if self.reexport {
+                vec![
+                    crate::module::Export::of::<Ledger>(),
+                    crate::module::Export::of::<Consumer>(),
+                ]
+            } else {
+                vec![]
+            }
+        }
+    }
+
+    #[test]
+    fn test_exports_are_shared_with_importer_and_siblings() {
+        let mut app = Application::new(ExportingAppModule { reexport: true });
+        app.bootstrap();
+
+        let ledger = app.injector().resolve::<Ledger>();
+        let consumer = app.injector().resolve::<Consumer>();
+
+        assert!(Shared::ptr_eq(&ledger, &consumer.ledger));
+        assert!(app.injector().try_resolve::<Secret>().is_err());
+    }
+
+    #[test]
+    fn test_exports_are_not_visible_without_reexport() {
+        let mut app = Application::new(ExportingAppModule { reexport: false });
+        app.bootstrap();
+
+        let err = app.injector().try_resolve::<Ledger>().unwrap_err();
+        assert!(err.kind == ErrorKind::ServiceNotProvided);
+    }
+
+    static POOL_DROPS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    struct Pool;
+
+    impl Drop for Pool {
+        fn drop(&mut self) {
+            POOL_DROPS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    struct PoolModule;
+
+    impl Module for PoolModule {
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<Pool>(Provider::singleton(|_| Shared::new(Pool)));
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Pool>()]
+        }
+    }
+
+    struct PoolAppModule;
+
+    impl Module for PoolAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(PoolModule)]
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Pool>()]
+        }
+    }
+
+    #[test]
+    fn test_dropping_application_drops_exported_singletons() {
+        let mut app = Application::new(PoolAppModule);
+        app.bootstrap();
+        app.injector().resolve::<Pool>();
+
+        drop(app);
+        assert_eq!(POOL_DROPS.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let mut app = Application::new(PoolAppModule);
+        app.bootstrap();
+        app.freeze().unwrap().resolve::<Pool>();
+
+        drop(app);
+        assert_eq!(POOL_DROPS.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    struct DanglingExportModule;
+
+    impl Module for DanglingExportModule {
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::named::<Ledger>("missing")]
+        }
+    }
+
+    #[test]
+    fn test_exporting_unprovided_type_fails_bootstrap() {
+        let mut app = Application::new(DanglingExportModule);
+
+        let err = app.try_bootstrap().unwrap_err();
+
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(
+            err.errors[0].module,
+            std::any::type_name::<DanglingExportModule>()
+        );
+        assert!(err.errors[0].error.kind == ErrorKind::ServiceNotProvided);
+        assert!(err.errors[0].error.message.contains("missing"));
+    }
+
+    struct DatabaseModule {
+        log: EventLog,
+    }
+
+    impl Module for DatabaseModule {
+        fn providers(&self, injector: &Injector) {
+            self.log.lock().unwrap().push("database".to_string());
+            injector.provide::<String>(Provider::root(|_| Shared::new("sqlite".to_string())));
+            injector.provide::<Ledger>(Provider::singleton(|_| Shared::new(Ledger)));
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Ledger>()]
+        }
+    }
+
+    struct FeatureModule<const N: usize> {
+        log: EventLog,
+    }
+
+    impl<const N: usize> Module for FeatureModule<N> {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(DatabaseModule {
+                log: self.log.clone(),
+            })]
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Ledger>()]
+        }
+    }
+
+    struct FeaturesAppModule {
+        log: EventLog,
+    }
+
+    impl Module for FeaturesAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![
+                Box::new(FeatureModule::<1> {
+                    log: self.log.clone(),
+                }),
+                Box::new(FeatureModule::<2> {
+                    log: self.log.clone(),
+                }),
+            ]
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+    }
+
+    #[test]
+    fn test_shared_import_is_loaded_once() {
+        let log = EventLog::default();
+        let mut app = Application::new(FeaturesAppModule { log: log.clone() });
+
+        app.try_bootstrap().unwrap();
+
+        assert_eq!(*log.lock().unwrap(), ["database"]);
+        assert_eq!(app.registry.modules().len(), 4);
+    }
+
+    #[test]
+    fn test_shared_import_exports_to_every_importer() {
+        let log = EventLog::default();
+        let mut app = Application::new(FeaturesAppModule { log });
+        app.bootstrap();
+
+        let first = app.registry.modules()[1].injector.resolve::<Ledger>();
+        let second = app.registry.modules()[2].injector.resolve::<Ledger>();
+
+        assert!(Shared::ptr_eq(&first, &second));
+    }
+
+    struct Connection(&'static str);
+
+    struct AccountsSecret(&'static str);
+
+    /// Shared by both feature modules; its factory looks for a provider that
+    /// only `AccountsModule` registers.
+    struct ConnectionModule;
+
+    impl Module for ConnectionModule {
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<Connection>(Provider::singleton(|injector| {
+                let secret = injector.optional_resolve::<AccountsSecret>();
+                Shared::new(Connection(secret.map(|secret| secret.0).unwrap_or("none")))
+            }));
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Connection>()]
+        }
+    }
+
+    struct AccountsModule;
+
+    impl Module for AccountsModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(ConnectionModule)]
+        }
+
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<AccountsSecret>(Provider::singleton(|_| {
+                Shared::new(AccountsSecret("accounts-private"))
+            }));
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Connection>()]
+        }
+    }
+
+    struct BillingModule;
+
+    impl Module for BillingModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(ConnectionModule)]
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Connection>()]
+        }
+    }
+
+    struct BillingAppModule {
+        accounts_first: bool,
+    }
+
+    impl Module for BillingAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            if self.accounts_first {
+                vec![Box::new(AccountsModule), Box::new(BillingModule)]
+            } else {
+                vec![Box::new(BillingModule), Box::new(AccountsModule)]
+            }
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Connection>()]
+        }
+    }
+
+    #[test]
+    fn test_shared_import_does_not_depend_on_import_order() {
+        for accounts_first in [true, false] {
+            let mut app = Application::new(BillingAppModule { accounts_first });
+            app.bootstrap();
+
+            assert_eq!(app.injector().resolve::<Connection>().0, "none");
+        }
+    }
+
+    struct Command(String);
+
+    struct HandlerPrefix(&'static str);
+
+    /// Contributes a Module-scoped handler built from a provider private to
+    /// its own injector.
+    struct HandlersModule {
+        prefix: &'static str,
+        command: &'static str,
+    }
+
+    impl Module for HandlersModule {
+        fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+            let prefix = self.prefix;
+            injector.try_provide::<HandlerPrefix>(Provider::singleton(move |_| {
+                Shared::new(HandlerPrefix(prefix))
+            }))?;
+
+            let command = self.command;
+            injector.try_provide_map_entry::<&str, Command>(
+                command,
+                Provider::singleton(move |injector| {
+                    let prefix = injector.resolve::<HandlerPrefix>();
+                    Shared::new(Command(format!("{}:{}", prefix.0, command)))
+                }),
+            )
+        }
+
+        fn options(&self) -> Option<ModuleOptions> {
+            Some(ModuleOptions::new(self.prefix))
+        }
+    }
+
+    struct DispatcherAppModule {
+        commands: [&'static str; 2],
+    }
+
+    impl Module for DispatcherAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![
+                Box::new(HandlersModule {
+                    prefix: "projects",
+                    command: self.commands[0],
+                }),
+                Box::new(HandlersModule {
+                    prefix: "users",
+                    command: self.commands[1],
+                }),
+            ]
+        }
+    }
+
+    #[test]
+    fn test_module_map_entries_are_collected_across_siblings() {
+        let mut app = Application::new(DispatcherAppModule {
+            commands: ["create", "delete"],
+        });
+        app.bootstrap();
+
+        let handlers = app.injector().resolve_map::<&str, Command>();
+        assert_eq!(handlers.len(), 2);
+        assert_eq!(handlers["create"].0, "projects:create");
+        assert_eq!(handlers["delete"].0, "users:delete");
+
+        // Each entry stays cached in the module that contributed it.
+        let module = app.registry.modules()[0].injector.clone();
+        let cached = module.resolve_map::<&str, Command>();
+        assert!(Shared::ptr_eq(&handlers["create"], &cached["create"]));
+        assert_eq!(cached.len(), 2);
+    }
+
+    #[test]
+    fn test_sibling_modules_cannot_contribute_the_same_map_key() {
+        let mut app = Application::new(DispatcherAppModule {
+            commands: ["create", "create"],
+        });
+
+        let err = app.try_bootstrap().unwrap_err();
+
+        assert_eq!(err.errors.len(), 1);
+        assert!(err.errors[0].error.kind == ErrorKind::DuplicateMapKey);
+    }
+
+    struct CycleA;
+    struct CycleB;
+
+    impl Module for CycleA {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(CycleB)]
+        }
+    }
+
+    impl Module for CycleB {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(CycleA)]
+        }
+    }
+
+    #[test]
+    fn test_circular_import_is_reported() {
+        let mut app = Application::new(CycleA);
+
+        let err = app.try_bootstrap().unwrap_err();
+
+        assert_eq!(err.errors.len(), 1);
+        assert!(err.errors[0].error.kind == ErrorKind::CircularImport);
+        assert_eq!(
+            err.errors[0].error.chain,
+            [
+                std::any::type_name::<CycleA>(),
+                std::any::type_name::<CycleB>(),
+                std::any::type_name::<CycleA>(),
+            ]
+        );
+    }
+
+    #[derive(Clone, Debug, Hash)]
+    struct CacheFeature(&'static str);
+
+    struct Cache {
+        prefix: String,
+    }
+
+    struct CacheModule {
+        feature: CacheFeature,
+        log: EventLog,
+    }
+
+    impl CacheModule {
+        fn for_feature(name: &'static str, log: &EventLog) -> Self {
+            Self {
+                feature: CacheFeature(name),
+                log: log.clone(),
+            }
+        }
+    }
+
+    impl Module for CacheModule {
+        fn options(&self) -> Option<crate::module::ModuleOptions> {
+            Some(crate::module::ModuleOptions::new(self.feature.clone()))
+        }
+
+        fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+            self.log.lock().unwrap().push(self.feature.0.to_string());
+            if self.feature.0.is_empty() {
+                return Err(Error::service_not_provided("Cache"));
+            }
+            injector.try_provide::<Cache>(Provider::singleton(|injector| {
+                let feature = injector.resolve::<CacheFeature>();
+                Shared::new(Cache {
+                    prefix: format!("{}:", feature.0),
+                })
+            }))
+        }
+    }
+
+    struct CachedAppModule {
+        features: Vec<&'static str>,
+        log: EventLog,
+    }
+
+    impl Module for CachedAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            self.features
+                .iter()
+                .map(|&feature| {
+                    Box::new(CacheModule::for_feature(feature, &self.log)) as Box<dyn Module>
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_configured_modules_load_once_per_options() {
+        let log = EventLog::default();
+        let mut app = Application::new(CachedAppModule {
+            features: vec!["users", "todos", "users"],
+            log: log.clone(),
+        });
+        app.try_bootstrap().unwrap();
+
+        assert_eq!(*log.lock().unwrap(), ["users", "todos"]);
+
+        let users = app.registry.modules()[0].injector.resolve::<Cache>();
+        let todos = app.registry.modules()[1].injector.resolve::<Cache>();
+        assert_eq!(users.prefix, "users:");
+        assert_eq!(todos.prefix, "todos:");
+    }
+
+    #[test]
+    fn test_module_errors_show_options() {
+        let log = EventLog::default();
+        let mut app = Application::new(CachedAppModule {
+            features: vec!["users", ""],
+            log,
+        });
+
+        let err = app.try_bootstrap().unwrap_err();
+
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].module, std::any::type_name::<CacheModule>());
+        assert_eq!(err.errors[0].options.as_deref(), Some("CacheFeature(\"\")"));
+        assert!(err.to_string().contains("CacheModule [CacheFeature(\"\")]"));
+    }
+
+    struct Clock;
+
+    struct ClockModule;
+
+    impl Module for ClockModule {
+        fn is_global(&self) -> bool {
+            true
+        }
+
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<Clock>(Provider::singleton(|_| Shared::new(Clock)));
+        }
+    }
+
+    struct ClockConsumerModule;
+
+    impl Module for ClockConsumerModule {
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<Shared<Clock>>(Provider::singleton(|injector| {
+                Shared::new(injector.resolve::<Clock>())
+            }));
+        }
+    }
+
+    struct GlobalAppModule;
+
+    impl Module for GlobalAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(ClockModule), Box::new(ClockConsumerModule)]
+        }
+    }
+
+    #[test]
+    fn test_global_module_providers_resolve_everywhere() {
+        let mut app = Application::new(GlobalAppModule);
+        app.bootstrap();
+
+        let modules = app.registry.modules();
+        let clock_injector = &modules[0].injector;
+        let consumer_injector = &modules[1].injector;
+
+        let from_root = app.injector().resolve::<Clock>();
+        let from_sibling = consumer_injector.resolve::<Shared<Clock>>();
+
+        assert!(Shared::ptr_eq(&from_root, &from_sibling));
+        assert!(Shared::ptr_eq(
+            &from_root,
+            &clock_injector.resolve::<Clock>()
+        ));
+    }
+
+    #[test]
+    fn test_global_module_keeps_module_scope() {
+        let mut app = Application::new(GlobalAppModule);
+        app.bootstrap();
+
+        app.injector().resolve::<Clock>();
+
+        assert!(app.injector().get_cached(&Key::of::<Clock>()).is_none());
+        assert!(
+            app.registry.modules()[0]
+                .injector
+                .get_cached(&Key::of::<Clock>())
+                .is_some()
+        );
+    }
+
+    #[derive(Debug)]
+    struct Report(&'static str);
+
+    struct ReportHelperModule {
+        log: EventLog,
+    }
+
+    impl Module for ReportHelperModule {
+        fn providers(&self, _injector: &Injector) {
+            self.log
+                .lock()
+                .unwrap()
+                .push("providers:helper".to_string());
+        }
+    }
+
+    struct ReportsModule {
+        log: EventLog,
+        fail: bool,
+    }
+
+    impl Module for ReportsModule {
+        fn is_lazy(&self) -> bool {
+            true
+        }
+
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(ReportHelperModule {
+                log: self.log.clone(),
+            })]
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Report>()]
+        }
+
+        fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+            self.log
+                .lock()
+                .unwrap()
+                .push("providers:reports".to_string());
+            if self.fail {
+                return Err(Error::service_not_provided("Printer"));
+            }
+            injector.try_provide::<Report>(Provider::singleton(|_| Shared::new(Report("monthly"))))
+        }
+
+        fn on_bootstrap(&self, _injector: &Injector) -> Result<(), Error> {
+            self.log
+                .lock()
+                .unwrap()
+                .push("bootstrap:reports".to_string());
+            Ok(())
+        }
+    }
+
+    struct CliModule {
+        log: EventLog,
+        fail: bool,
+    }
+
+    impl Module for CliModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(ReportsModule {
+                log: self.log.clone(),
+                fail: self.fail,
+            })]
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Report>()]
+        }
+    }
+
+    #[test]
+    fn test_lazy_module_loads_on_first_resolve() {
+        let log = EventLog::default();
+        let mut app = Application::new(CliModule {
+            log: log.clone(),
+            fail: false,
+        });
+        app.bootstrap();
+
+        assert!(log.lock().unwrap().is_empty());
+        assert_eq!(app.registry.modules().len(), 1);
+
+        let first = app.injector().resolve::<Report>();
+        let second = app.injector().resolve::<Report>();
+
+        assert_eq!(first.0, "monthly");
+        assert!(Shared::ptr_eq(&first, &second));
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["providers:helper", "providers:reports", "bootstrap:reports"]
+        );
+        assert_eq!(app.registry.modules().len(), 3);
+    }
+
+    #[test]
+    fn test_lazy_module_failure_is_returned_by_resolution() {
+        let log = EventLog::default();
+        let mut app = Application::new(CliModule {
+            log: log.clone(),
+            fail: true,
+        });
+        app.bootstrap();
+
+        let err = app.injector().try_resolve::<Report>().unwrap_err();
+        assert!(err.kind == ErrorKind::ModuleLoadFailed);
+        assert!(err.message.contains("Printer"));
+
+        let again = app.injector().try_resolve::<Report>().unwrap_err();
+        assert!(again.kind == ErrorKind::ModuleLoadFailed);
+        assert_eq!(
+            log.lock()
+                .unwrap()
+                .iter()
+                .filter(|event| *event == "providers:reports")
+                .count(),
+            1
+        );
+    }
+
+    struct Summary;
+
+    /// Lazy module exporting a type it never provides.
+    struct SummaryModule;
+
+    impl Module for SummaryModule {
+        fn is_lazy(&self) -> bool {
+            true
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Summary>()]
+        }
+    }
+
+    struct SummaryAppModule;
+
+    impl Module for SummaryAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(SummaryModule)]
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Summary>()]
+        }
+    }
+
+    #[test]
+    fn test_lazy_module_missing_export_fails_resolution() {
+        let mut app = Application::new(SummaryAppModule);
+        assert!(app.try_bootstrap().is_ok());
+
+        let err = app.injector().try_resolve::<Summary>().err().unwrap();
+        assert!(err.kind == ErrorKind::ModuleLoadFailed);
+        assert!(err.message.contains("Cannot export type"));
+        assert!(err.message.contains("Summary"));
+    }
+
+    /// Lazy module whose providers panic.
+    struct PanickingSummaryModule;
+
+    impl Module for PanickingSummaryModule {
+        fn is_lazy(&self) -> bool {
+            true
+        }
+
+        fn providers(&self, _injector: &Injector) {
+            panic!("summary store unavailable");
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Summary>()]
+        }
+    }
+
+    struct PanickingSummaryAppModule;
+
+    impl Module for PanickingSummaryAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(PanickingSummaryModule)]
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Summary>()]
+        }
+    }
+
+    #[test]
+    fn test_lazy_module_panic_fails_later_resolutions() {
+        let mut app = Application::new(PanickingSummaryAppModule);
+        app.bootstrap();
+
+        let injector = app.injector();
+        let first = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = injector.try_resolve::<Summary>();
+        }));
+        assert!(first.is_err());
+
+        let err = injector.try_resolve::<Summary>().err().unwrap();
+        assert!(err.kind == ErrorKind::ModuleLoadFailed);
+        assert!(err.message.contains("panicked"));
+    }
+
+    /// Lazy module whose providers take a while to register.
+    #[cfg(feature = "thread-safe")]
+    struct SlowReportsModule {
+        log: EventLog,
+    }
+
+    #[cfg(feature = "thread-safe")]
+    impl Module for SlowReportsModule {
+        fn is_lazy(&self) -> bool {
+            true
+        }
+
+        fn providers(&self, injector: &Injector) {
+            self.log.lock().unwrap().push("providers:slow".to_string());
+            std::thread::sleep(std::time::Duration::from_millis(100));
+            injector.provide::<Report>(Provider::singleton(|_| Shared::new(Report("slow"))));
+        }
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Report>()]
+        }
+    }
+
+    #[cfg(feature = "thread-safe")]
+    struct SlowCliModule {
+        log: EventLog,
+    }
+
+    #[cfg(feature = "thread-safe")]
+    impl Module for SlowCliModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(SlowReportsModule {
+                log: self.log.clone(),
+            })]
+        }
+
+        fn providers(&self, _injector: &Injector) {}
+
+        fn exports(&self) -> Vec<crate::module::Export> {
+            vec![crate::module::Export::of::<Report>()]
+        }
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_lazy_module_loading_blocks_other_threads() {
+        let log = EventLog::default();
+        let mut app = Application::new(SlowCliModule { log: log.clone() });
+        app.bootstrap();
+
+        let injector = app.injector();
+        let loader = std::thread::spawn(move || injector.resolve::<Report>());
+
+        while log.lock().unwrap().is_empty() {
+            std::thread::yield_now();
+        }
+        let waiting = app.injector().try_resolve::<Report>().unwrap();
+
+        assert!(Shared::ptr_eq(&waiting, &loader.join().unwrap()));
+        assert_eq!(*log.lock().unwrap(), ["providers:slow"]);
+    }
+
+    struct DbClient;
+
+    struct DbModule {
+        log: EventLog,
+        url: &'static str,
+    }
+
+    impl Module for DbModule {
+        fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+            let log = self.log.clone();
+            let url = self.url;
+            injector.try_provide::<DbClient>(
+                Provider::try_singleton(move |_| {
+                    log.lock().unwrap().push("connect".to_string());
+                    if url.is_empty() {
+                        return Err("missing url");
+                    }
+                    Ok(Shared::new(DbClient))
+                })
+                .eager(),
+            )?;
+
+            let log = self.log.clone();
+            injector.try_provide::<Greeting>(Provider::singleton(move |injector| {
+                injector.resolve::<DbClient>();
+                log.lock().unwrap().push("greeting".to_string());
+                Shared::new(Greeting("hi"))
+            }))
+        }
+
+        fn on_bootstrap(&self, _injector: &Injector) -> Result<(), Error> {
+            self.log.lock().unwrap().push("bootstrap".to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_eager_providers_are_instantiated_at_bootstrap() {
+        let log = EventLog::default();
+        let mut app = Application::new(DbModule {
+            log: log.clone(),
+            url: "sqlite::memory:",
+        });
+        app.try_bootstrap().unwrap();
+
+        assert_eq!(*log.lock().unwrap(), ["connect", "bootstrap"]);
+
+        let injector = app.registry.modules()[0].injector.clone();
+        injector.resolve::<Greeting>();
+        assert_eq!(*log.lock().unwrap(), ["connect", "bootstrap", "greeting"]);
+    }
+
+    #[test]
+    fn test_eager_failure_fails_bootstrap() {
+        let log = EventLog::default();
+        let mut app = Application::new(DbModule {
+            log: log.clone(),
+            url: "",
+        });
+
+        let err = app.try_bootstrap().unwrap_err();
+
+        assert_eq!(err.errors.len(), 1);
+        assert_eq!(err.errors[0].module, std::any::type_name::<DbModule>());
+        assert!(err.errors[0].error.kind == ErrorKind::FactoryFailed);
+        assert_eq!(*log.lock().unwrap(), ["connect"]);
+    }
+
+    struct EagerIgnoredModule {
+        log: EventLog,
+    }
+
+    impl Module for EagerIgnoredModule {
+        fn providers(&self, injector: &Injector) {
+            let log = self.log.clone();
+            injector.provide::<DbClient>(
+                Provider::transient(move |_| {
+                    log.lock().unwrap().push("transient".to_string());
+                    Shared::new(DbClient)
+                })
+                .eager(),
+            );
+
+            let log = self.log.clone();
+            injector.provide::<Greeting>(
+                Provider::scoped(move |_| {
+                    log.lock().unwrap().push("scoped".to_string());
+                    Shared::new(Greeting("hi"))
+                })
+                .eager(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_eager_is_ignored_on_transient_and_scoped_providers() {
+        let log = EventLog::default();
+        let mut app = Application::new(EagerIgnoredModule { log: log.clone() });
+
+        app.try_bootstrap().unwrap();
+
+        assert!(log.lock().unwrap().is_empty());
+    }
+
+    struct Label(&'static str);
+
+    struct Banner {
+        label: Shared<Label>,
+    }
+
+    struct RegionModule<const ID: usize>;
+
+    impl<const ID: usize> Module for RegionModule<ID> {
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<Label>(Provider::singleton(|_| {
+                Shared::new(Label(["eu", "us"][ID]))
+            }));
+            if ID == 0 {
+                injector.provide::<Banner>(Provider::try_root(|injector| {
+                    injector
+                        .try_resolve::<Label>()
+                        .map(|label| Shared::new(Banner { label }))
+                }));
+            }
+        }
+    }
+
+    struct RegionsAppModule;
+
+    impl Module for RegionsAppModule {
+        fn imports(&self) -> Vec<Box<dyn Module>> {
+            vec![Box::new(RegionModule::<0>), Box::new(RegionModule::<1>)]
+        }
+
+        fn providers(&self, injector: &Injector) {
+            injector.provide::<Label>(Provider::root(|_| Shared::new(Label("global"))));
+        }
+    }
+
+    #[test]
+    fn test_root_singletons_do_not_depend_on_first_resolver() {
+        // Resolve first from the root injector, then from each module.
+        for first in 0..3 {
+            let mut app = Application::new(RegionsAppModule);
+            // Without it, resolving with a module's injector would fail
+            // instead of capturing that module's Label.
+            app.injector().set_captive_policy(CaptivePolicy::Allow);
+            app.bootstrap();
+
+            let modules = app.registry.modules();
+            let injectors = [
+                (*app.injector()).clone(),
+                (*modules[0].injector).clone(),
+                (*modules[1].injector).clone(),
+            ];
+
+            assert_eq!(injectors[1].resolve::<Label>().0, "eu");
+            assert_eq!(injectors[2].resolve::<Label>().0, "us");
+
+            let banner = injectors[first].resolve::<Banner>();
+            assert_eq!(banner.label.0, "global");
+
+            for injector in &injectors {
+                assert!(Shared::ptr_eq(&banner, &injector.resolve::<Banner>()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_frozen_application_resolves_the_same_instances() {
+        let mut app = Application::new(ExportingAppModule { reexport: true });
+        app.bootstrap();
+        let ledger = app.injector().resolve::<Ledger>();
+
+        let frozen = app.freeze().unwrap();
+        let consumer = frozen.resolve::<Consumer>();
+
+        assert!(Shared::ptr_eq(&ledger, &frozen.resolve::<Ledger>()));
+        assert!(Shared::ptr_eq(&ledger, &consumer.ledger));
+        assert!(Shared::ptr_eq(
+            &consumer,
+            &app.injector().resolve::<Consumer>()
+        ));
+
+        let err = frozen.try_resolve::<Secret>().err().unwrap();
+        assert!(err.kind == ErrorKind::ServiceNotProvided);
+    }
+
+    #[test]
+    fn test_frozen_module_injectors_keep_their_own_providers() {
+        let mut app = Application::new(RegionsAppModule);
+        app.injector().set_captive_policy(CaptivePolicy::Allow);
+        app.bootstrap();
+
+        let frozen = app.freeze().unwrap();
+        let modules = app.registry.modules();
+
+        assert_eq!(modules[0].injector.resolve::<Label>().0, "eu");
+        assert_eq!(modules[1].injector.resolve::<Label>().0, "us");
+        assert_eq!(frozen.resolve::<Label>().0, "global");
+        assert_eq!(frozen.resolve::<Banner>().label.0, "global");
+    }
+
+    #[test]
+    fn test_frozen_injectors_reject_providers() {
+        let mut app = Application::new(ExportingAppModule { reexport: true });
+        app.bootstrap();
+        app.freeze().unwrap();
+
+        let module = app.registry.modules()[0].injector.clone();
+        let errors = [
+            app.injector()
+                .try_provide::<u32>(Provider::root(|_| Shared::new(1)))
+                .err()
+                .unwrap(),
+            module
+                .try_provide::<u32>(Provider::singleton(|_| Shared::new(1)))
+                .err()
+                .unwrap(),
+            module
+                .try_provide_multi::<u32>(Provider::transient(|_| Shared::new(1)))
+                .err()
+                .unwrap(),
+            module
+                .try_provide_map_entry::<&str, u32>("one", Provider::transient(|_| Shared::new(1)))
+                .err()
+                .unwrap(),
+        ];
+        for error in errors {
+            assert!(error.kind == ErrorKind::Frozen);
+        }
+
+        // Injectors created afterwards are not frozen.
+        let child = Injector::child(app.injector());
+        child.provide::<u32>(Provider::singleton(|_| Shared::new(7)));
+        assert_eq!(*child.resolve::<u32>(), 7);
+    }
+
+    #[test]
+    fn test_freeze_loads_lazy_modules() {
+        let log = EventLog::default();
+        let mut app = Application::new(CliModule {
+            log: log.clone(),
+            fail: false,
+        });
+        app.bootstrap();
+
+        let frozen = app.freeze().unwrap();
+        assert_eq!(app.registry.modules().len(), 3);
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["providers:helper", "providers:reports", "bootstrap:reports"]
+        );
+        assert_eq!(frozen.resolve::<Report>().0, "monthly");
+    }
+
+    #[test]
+    fn test_freeze_fails_when_a_lazy_module_fails() {
+        let mut app = Application::new(CliModule {
+            log: EventLog::default(),
+            fail: true,
+        });
+        app.bootstrap();
+
+        let err = app.freeze().err().unwrap();
+        assert!(err.kind == ErrorKind::ModuleLoadFailed);
+        assert!(err.message.contains("Printer"));
+    }
 }
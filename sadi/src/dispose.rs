@@ -0,0 +1,69 @@
+//! Teardown hooks for services owned by the container.
+//!
+//! A provider opts into disposal with [`Provider::with_dispose`] or
+//! [`Provider::with_async_dispose`]. Every Module or Root singleton it builds
+//! is then recorded by the injector that caches it, and disposed by
+//! [`Injector::dispose`] (or [`Application::shutdown`]) in reverse creation
+//! order. Transient instances are never owned by the container, so they are
+//! not disposed.
+//!
+//! # Examples
+//!
+//! ```
+//! use sadi::{Dispose, DisposeError, Injector, Provider, Shared};
+//!
+//! struct Connection;
+//!
+//! impl Dispose for Connection {
+//!     fn dispose(&self) -> Result<(), DisposeError> {
+//!         // Close the connection
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let injector = Injector::root();
+//! injector.provide::<Connection>(Provider::root(|_| Shared::new(Connection)).with_dispose());
+//!
+//! injector.resolve::<Connection>();
+//! assert!(injector.dispose().is_ok());
+//! ```
+//!
+//! [`Provider::with_dispose`]: crate::provider::Provider::with_dispose
+//! [`Provider::with_async_dispose`]: crate::provider::Provider::with_async_dispose
+//! [`Injector::dispose`]: crate::injector::Injector::dispose
+//! [`Application::shutdown`]: crate::application::Application::shutdown
+
+use crate::runtime::{BoxFuture, Shared};
+
+/// Error returned by a dispose hook.
+pub type DisposeError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Synchronous teardown of a service.
+pub trait Dispose {
+    /// Releases the resources held by this service.
+    fn dispose(&self) -> Result<(), DisposeError>;
+}
+
+/// Asynchronous teardown of a service.
+///
+/// Providers registered with [`Provider::with_async_dispose`](crate::provider::Provider::with_async_dispose)
+/// are disposed by [`Injector::dispose_async`](crate::injector::Injector::dispose_async)
+/// and [`Application::shutdown_async`](crate::application::Application::shutdown_async).
+pub trait AsyncDispose {
+    /// Releases the resources held by this service.
+    fn dispose_async(&self) -> BoxFuture<'_, Result<(), DisposeError>>;
+}
+
+/// The dispose hook stored by a provider.
+pub enum DisposeHook<T: ?Sized + 'static> {
+    Sync(fn(&T) -> Result<(), DisposeError>),
+    Async(fn(Shared<T>) -> BoxFuture<'static, Result<(), DisposeError>>),
+}
+
+impl<T: ?Sized + 'static> Clone for DisposeHook<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized + 'static> Copy for DisposeHook<T> {}
@@ -7,7 +7,8 @@
 //! # Design
 //!
 //! - `ErrorKind` captures the error category.
-//! - `Error` stores the category and a human-readable message.
+//! - `Error` stores the category, a human-readable message and, for factory
+//!   failures, the underlying error as its `source`.
 //!
 //! The helpers in `Error` are provided to keep call sites concise and to
 //! maintain consistent error messages.
@@ -27,6 +28,7 @@
 //! ```
 
 use core::fmt;
+use std::sync::Arc;
 
 #[cfg(feature = "tracing")]
 use tracing::error;
@@ -46,16 +48,48 @@ pub enum ErrorKind {
     ProviderAlreadyRegistered,
     /// Circular dependency detected in resolution chain.
     CircularDependency,
+    /// A fallible factory returned an error while building the instance.
+    FactoryFailed,
+    /// An async provider was resolved through the synchronous API.
+    AsyncFactory,
+    /// Two map multi-binding entries share the same key.
+    DuplicateMapKey,
+    /// The application was bootstrapped more than once, including after a
+    /// failed bootstrap.
+    AlreadyBootstrapped,
+    /// A dispose hook failed, or an async hook was run synchronously.
+    DisposeFailed,
+    /// Modules import each other in a cycle.
+    CircularImport,
+    /// A lazy module failed to load when first used.
+    ModuleLoadFailed,
+    /// A service depends on one with a shorter-lived scope.
+    CaptiveDependency,
+    /// A Scoped service was resolved outside of a scope.
+    NoActiveScope,
+    /// Waiting for another thread to build a singleton took longer than the
+    /// injector's init timeout.
+    InitTimeout,
+    /// A provider was registered in an injector frozen by
+    /// `Application::freeze`.
+    Frozen,
 }
 
 /// Container error structure.
 ///
 /// `kind` enables programmatic handling, while `message` is human-readable.
+/// `source` holds the original error reported by a fallible factory,
+/// `chain` lists the types forming a circular dependency, and `path` lists
+/// the types being resolved when the error occurred, from the requested type
+/// down to the failing dependency.
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Error {
     pub kind: ErrorKind,
     pub message: String,
+    pub source: Option<Arc<dyn std::error::Error + Send + Sync + 'static>>,
+    pub chain: Vec<String>,
+    pub path: Vec<String>,
 }
 
 impl Error {
@@ -66,6 +100,9 @@ impl Error {
         let error = Self {
             kind: kind.clone(),
             message: message.into(),
+            source: None,
+            chain: Vec::new(),
+            path: Vec::new(),
         };
 
         #[cfg(feature = "tracing")]
@@ -105,14 +142,257 @@ impl Error {
         )
     }
 
+    /// A module exports a type it does not provide.
+    pub fn export_not_provided(type_name: &str) -> Self {
+        Self::new(
+            ErrorKind::ServiceNotProvided,
+            format!(
+                "Cannot export type: {} because the module does not provide it",
+                type_name
+            ),
+        )
+    }
+
     /// Circular dependency detected in resolution chain.
+    ///
+    /// The chain starts and ends with the type that was requested twice and
+    /// is also kept in the `chain` field.
     pub fn circular_dependency(dependency_chain: &[&str]) -> Self {
-        Self::new(
+        let mut error = Self::new(
             ErrorKind::CircularDependency,
             format!(
                 "Circular dependency detected: {}",
                 dependency_chain.join(" -> ")
             ),
+        );
+        error.chain = dependency_chain
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        error
+    }
+
+    /// Singleton constructions running on different threads wait for each
+    /// other.
+    ///
+    /// `dependency_chain` is kept in `chain` as for
+    /// [`circular_dependency`](Error::circular_dependency); the message also
+    /// describes what each of the `threads` involved was resolving.
+    pub fn circular_wait(dependency_chain: &[&str], threads: &[String]) -> Self {
+        let mut error = Self::new(
+            ErrorKind::CircularDependency,
+            format!(
+                "Circular dependency detected: {} (across threads: {})",
+                dependency_chain.join(" -> "),
+                threads.join("; ")
+            ),
+        );
+        error.chain = dependency_chain
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        error
+    }
+
+    /// Modules import each other in a cycle.
+    ///
+    /// Like [`circular_dependency`](Error::circular_dependency), the chain
+    /// starts and ends with the module imported twice and is kept in `chain`.
+    pub fn circular_import(import_chain: &[&str]) -> Self {
+        let mut error = Self::new(
+            ErrorKind::CircularImport,
+            format!("Circular import detected: {}", import_chain.join(" -> ")),
+        );
+        error.chain = import_chain.iter().map(|name| name.to_string()).collect();
+        error
+    }
+
+    /// A lazy module, or one of its imports, failed to load on first use.
+    ///
+    /// No `errors` means the application owning the module was dropped.
+    pub fn lazy_module_failed(module_name: &str, errors: &[ModuleError]) -> Self {
+        let reason = if errors.is_empty() {
+            "the application was dropped".to_string()
+        } else {
+            let errors: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+            errors.join("; ")
+        };
+
+        Self::new(
+            ErrorKind::ModuleLoadFailed,
+            format!("Lazy module {} failed to load: {}", module_name, reason),
+        )
+    }
+
+    /// A lazy module panicked while loading, so it never will.
+    pub fn lazy_module_panicked(module_name: &str) -> Self {
+        Self::new(
+            ErrorKind::ModuleLoadFailed,
+            format!("Lazy module {} panicked while loading", module_name),
+        )
+    }
+
+    /// A fallible factory failed to build the requested type.
+    ///
+    /// The original error is kept as the `source` of the returned error, and
+    /// left out of its message so that error reporters print it once.
+    pub fn factory_failed(
+        type_name: &str,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    ) -> Self {
+        let source: Arc<dyn std::error::Error + Send + Sync + 'static> = Arc::from(source.into());
+
+        let mut error = Self::new(
+            ErrorKind::FactoryFailed,
+            format!("Factory failed for type: {}", type_name),
+        );
+        error.source = Some(source);
+        error
+    }
+
+    /// The application has already been bootstrapped.
+    pub fn already_bootstrapped() -> Self {
+        Self::new(
+            ErrorKind::AlreadyBootstrapped,
+            "Application already bootstrapped",
+        )
+    }
+
+    /// The application was bootstrapped again after its bootstrap failed.
+    pub fn bootstrap_already_failed() -> Self {
+        Self::new(
+            ErrorKind::AlreadyBootstrapped,
+            "Application bootstrap already failed, create a new application to retry",
+        )
+    }
+
+    /// A dispose hook failed while tearing down the given type.
+    ///
+    /// The original error is kept as the `source` of the returned error, and
+    /// left out of its message so that error reporters print it once.
+    pub fn dispose_failed(
+        type_name: &str,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    ) -> Self {
+        let source: Arc<dyn std::error::Error + Send + Sync + 'static> = Arc::from(source.into());
+
+        let mut error = Self::new(
+            ErrorKind::DisposeFailed,
+            format!("Dispose failed for type: {}", type_name),
+        );
+        error.source = Some(source);
+        error
+    }
+
+    /// The type has an async dispose hook and was disposed synchronously.
+    pub fn async_dispose(type_name: &str) -> Self {
+        Self::new(
+            ErrorKind::DisposeFailed,
+            format!(
+                "Type: {} has an async dispose hook; use dispose_async or shutdown_async",
+                type_name
+            ),
+        )
+    }
+
+    /// Converts the error returned by a fallible factory.
+    ///
+    /// A container error coming from a nested resolution is passed through
+    /// unchanged; any other error becomes a `FactoryFailed` error.
+    pub(crate) fn from_factory(
+        type_name: &str,
+        error: Box<dyn std::error::Error + Send + Sync + 'static>,
+    ) -> Self {
+        #[cfg(feature = "debug")]
+        let error = match error.downcast::<Self>() {
+            Ok(error) => return *error,
+            Err(error) => error,
+        };
+
+        Self::factory_failed(type_name, error)
+    }
+
+    /// Records the resolution path, unless a nested resolution already did.
+    pub(crate) fn with_path(mut self, path: impl FnOnce() -> Vec<String>) -> Self {
+        if self.path.is_empty() {
+            self.path = path();
+        }
+        self
+    }
+
+    /// The provider for this type has an async factory.
+    ///
+    /// Returned when such a provider is resolved with `try_resolve` before an
+    /// instance was cached by `try_resolve_async`.
+    pub fn async_factory(type_name: &str) -> Self {
+        Self::new(
+            ErrorKind::AsyncFactory,
+            format!(
+                "Provider for type: {} has an async factory; use resolve_async",
+                type_name
+            ),
+        )
+    }
+
+    /// A service depends on one with a shorter-lived scope, which would be
+    /// kept alive past its lifetime inside it.
+    pub fn captive_dependency(
+        dependent: &str,
+        dependent_scope: &str,
+        dependency: &str,
+        dependency_scope: &str,
+    ) -> Self {
+        Self::new(
+            ErrorKind::CaptiveDependency,
+            format!(
+                "Captive dependency: {} ({} scope) cannot depend on {} ({} scope)",
+                dependent, dependent_scope, dependency, dependency_scope
+            ),
+        )
+    }
+
+    /// A Scoped service was resolved from an injector that is not inside a
+    /// scope.
+    pub fn no_active_scope(type_name: &str) -> Self {
+        Self::new(
+            ErrorKind::NoActiveScope,
+            format!(
+                "No active scope for Scoped service: {}; resolve it from Injector::create_scope",
+                type_name
+            ),
+        )
+    }
+
+    /// Another thread did not finish building a singleton within `timeout`.
+    pub fn init_timeout(type_name: &str, timeout: std::time::Duration) -> Self {
+        Self::new(
+            ErrorKind::InitTimeout,
+            format!(
+                "Timed out after {:?} waiting for another thread to build: {}",
+                timeout, type_name
+            ),
+        )
+    }
+
+    /// A provider was registered in an injector that has been frozen.
+    pub fn frozen(type_name: &str) -> Self {
+        Self::new(
+            ErrorKind::Frozen,
+            format!(
+                "Cannot register a provider for {}: the injector is frozen",
+                type_name
+            ),
+        )
+    }
+
+    /// Two contributions to the same map multi-binding use the same key.
+    pub fn duplicate_map_key(map_name: &str, key: &str) -> Self {
+        Self::new(
+            ErrorKind::DuplicateMapKey,
+            format!(
+                "Map entry with key {} contributed more than once for: {}",
+                key, map_name
+            ),
         )
     }
 }
@@ -120,18 +400,121 @@ impl Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         #[cfg(feature = "debug")]
-        {
-            write!(f, "({:?}) - {}", self.kind, self.message)
-        }
+        write!(f, "({:?}) - {}", self.kind, self.message)?;
         #[cfg(not(feature = "debug"))]
-        {
-            write!(f, "{}", self.message)
+        write!(f, "{}", self.message)?;
+
+        if !self.path.is_empty() {
+            write!(f, " (resolving {})", self.path.join(" -> "))?;
         }
+
+        Ok(())
     }
 }
 
 #[cfg(feature = "debug")]
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// An error raised while loading one module.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ModuleError {
+    /// [`type_name`](crate::module::Module::type_name) of the offending module.
+    pub module: &'static str,
+    /// Description of the module's [`options`](crate::module::Module::options),
+    /// if it is a configured module.
+    pub options: Option<String>,
+    pub error: Error,
+}
+
+impl ModuleError {
+    /// Creates an error for a module that has no options.
+    pub fn new(module: &'static str, error: Error) -> Self {
+        Self {
+            module,
+            options: None,
+            error,
+        }
+    }
+}
+
+impl fmt::Display for ModuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.options {
+            Some(options) => write!(f, "{} [{}]: {}", self.module, options, self.error),
+            None => write!(f, "{}: {}", self.module, self.error),
+        }
+    }
+}
+
+/// Every failure collected by
+/// [`Application::try_bootstrap`](crate::application::Application::try_bootstrap).
+///
+/// Bootstrap keeps loading the rest of the import tree after a module fails,
+/// so `errors` lists failures from all modules in load order.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct BootstrapError {
+    pub errors: Vec<ModuleError>,
+}
+
+impl fmt::Display for BootstrapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bootstrap failed with {} error(s)", self.errors.len())?;
+
+        for error in &self.errors {
+            write!(f, "\n  - {}", error)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "debug")]
+impl std::error::Error for BootstrapError {}
+
+/// Every dispose failure collected while tearing services down.
+///
+/// Returned by [`Injector::dispose`](crate::injector::Injector::dispose) and
+/// [`Application::shutdown`](crate::application::Application::shutdown), which
+/// keep disposing after a hook fails. `errors` lists the failures in dispose
+/// order.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ShutdownError {
+    pub errors: Vec<Error>,
+}
+
+impl ShutdownError {
+    pub(crate) fn check(errors: Vec<Error>) -> Result<(), Self> {
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Self { errors })
+        }
+    }
+}
+
+impl fmt::Display for ShutdownError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Shutdown failed with {} error(s)", self.errors.len())?;
+
+        for error in &self.errors {
+            write!(f, "\n  - {}", error)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "debug")]
+impl std::error::Error for ShutdownError {}
 
 #[cfg(test)]
 mod tests {
@@ -145,6 +528,14 @@ mod tests {
         assert!(err.message.contains("provider"));
     }
 
+    #[test]
+    fn export_not_provided_error() {
+        let err = Error::export_not_provided("Repo");
+        assert!(err.kind == ErrorKind::ServiceNotProvided);
+        assert!(err.message.contains("export"));
+        assert!(err.message.contains("Repo"));
+    }
+
     #[test]
     fn type_mismatch_error() {
         let err = Error::type_mismatch("OtherType");
@@ -160,12 +551,102 @@ mod tests {
         assert!(err.message.contains("transient"));
     }
 
+    #[test]
+    fn frozen_error() {
+        let err = Error::frozen("Foo");
+        assert!(err.kind == ErrorKind::Frozen);
+        assert!(err.message.contains("Foo"));
+        assert!(err.message.contains("frozen"));
+    }
+
     #[test]
     fn circular_dependency_error() {
         let chain = ["A", "B", "A"];
         let err = Error::circular_dependency(&chain);
         assert!(err.kind == ErrorKind::CircularDependency);
         assert!(err.message.contains("A -> B -> A"));
+        assert_eq!(err.chain, ["A", "B", "A"]);
+    }
+
+    #[test]
+    fn circular_wait_error() {
+        let threads = [
+            "thread 'a' resolving A -> B".to_string(),
+            "thread 'b' resolving B -> A".to_string(),
+        ];
+        let err = Error::circular_wait(&["A", "B", "A"], &threads);
+        assert!(err.kind == ErrorKind::CircularDependency);
+        assert!(err.message.contains("A -> B -> A"));
+        assert!(err.message.contains(&threads[1]));
+        assert_eq!(err.chain, ["A", "B", "A"]);
+    }
+
+    #[test]
+    fn circular_import_error() {
+        let chain = ["AModule", "BModule", "AModule"];
+        let err = Error::circular_import(&chain);
+        assert!(err.kind == ErrorKind::CircularImport);
+        assert!(err.message.contains("AModule -> BModule -> AModule"));
+        assert_eq!(err.chain, chain);
+    }
+
+    #[test]
+    fn lazy_module_failed_error() {
+        let errors = [ModuleError::new(
+            "app::ReportsModule",
+            Error::service_not_provided("Printer"),
+        )];
+        let err = Error::lazy_module_failed("app::ReportsModule", &errors);
+        assert!(err.kind == ErrorKind::ModuleLoadFailed);
+        assert!(
+            err.message
+                .contains("Lazy module app::ReportsModule failed to load")
+        );
+        assert!(err.message.contains("Printer"));
+    }
+
+    #[test]
+    fn lazy_module_panicked_error() {
+        let err = Error::lazy_module_panicked("app::ReportsModule");
+        assert!(err.kind == ErrorKind::ModuleLoadFailed);
+        assert!(err.message.contains("app::ReportsModule"));
+        assert!(err.message.contains("panicked"));
+    }
+
+    #[test]
+    fn factory_failed_error() {
+        let err = Error::factory_failed("Database", "connection refused");
+        assert!(err.kind == ErrorKind::FactoryFailed);
+        assert_eq!(err.message, "Factory failed for type: Database");
+        assert_eq!(err.source.unwrap().to_string(), "connection refused");
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    fn factory_failed_exposes_source() {
+        use std::error::Error as _;
+
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "missing.db");
+        let err = Error::factory_failed("Database", io);
+        let source = err.source().expect("source should be set");
+        assert_eq!(source.to_string(), "missing.db");
+        assert!(source.downcast_ref::<std::io::Error>().is_some());
+    }
+
+    #[test]
+    fn async_factory_error() {
+        let err = Error::async_factory("Pool");
+        assert!(err.kind == ErrorKind::AsyncFactory);
+        assert!(err.message.contains("Pool"));
+        assert!(err.message.contains("resolve_async"));
+    }
+
+    #[test]
+    fn duplicate_map_key_error() {
+        let err = Error::duplicate_map_key("Handlers", "\"create\"");
+        assert!(err.kind == ErrorKind::DuplicateMapKey);
+        assert!(err.message.contains("\"create\""));
+        assert!(err.message.contains("Handlers"));
     }
 
     #[test]
@@ -177,6 +658,86 @@ mod tests {
         assert!(s.contains("X"));
     }
 
+    #[test]
+    fn display_includes_resolution_path() {
+        let err =
+            Error::service_not_provided("Pool").with_path(|| vec!["Repo".into(), "Pool".into()]);
+        assert!(format!("{}", err).contains("(resolving Repo -> Pool)"));
+    }
+
+    #[test]
+    fn with_path_keeps_nested_path() {
+        let err = Error::type_mismatch("C")
+            .with_path(|| vec!["B".into(), "C".into()])
+            .with_path(|| vec!["B".into()]);
+        assert_eq!(err.path, ["B", "C"]);
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    fn from_factory_passes_container_errors_through() {
+        let nested = Error::service_not_provided("Pool");
+        let err = Error::from_factory("Repo", Box::new(nested));
+        assert!(err.kind == ErrorKind::ServiceNotProvided);
+
+        let err = Error::from_factory("Repo", "boom".into());
+        assert!(err.kind == ErrorKind::FactoryFailed);
+    }
+
+    #[test]
+    fn bootstrap_error_lists_every_module() {
+        let err = BootstrapError {
+            errors: vec![
+                ModuleError::new(
+                    "app::DatabaseModule",
+                    Error::provider_already_registered("Pool", "Root"),
+                ),
+                ModuleError {
+                    module: "app::CacheModule",
+                    options: Some("Feature(\"users\")".to_string()),
+                    error: Error::service_not_provided("Redis"),
+                },
+            ],
+        };
+
+        let s = err.to_string();
+        assert!(s.contains("2 error(s)"));
+        assert!(s.contains("app::DatabaseModule: "));
+        assert!(s.contains("app::CacheModule [Feature(\"users\")]: "));
+    }
+
+    #[test]
+    fn dispose_failed_error() {
+        let err = Error::dispose_failed("Pool", "socket closed");
+        assert!(err.kind == ErrorKind::DisposeFailed);
+        assert!(err.message.contains("Pool"));
+        assert!(!err.message.contains("socket closed"));
+        assert_eq!(err.source.unwrap().to_string(), "socket closed");
+    }
+
+    #[test]
+    fn shutdown_error_lists_every_failure() {
+        let err = ShutdownError {
+            errors: vec![
+                Error::dispose_failed("Pool", "timeout"),
+                Error::async_dispose("Cache"),
+            ],
+        };
+
+        let s = err.to_string();
+        assert!(s.contains("2 error(s)"));
+        assert!(s.contains("Pool"));
+        assert!(s.contains("dispose_async"));
+    }
+
+    #[test]
+    fn captive_dependency_error() {
+        let err = Error::captive_dependency("Repo", "Root", "Transaction", "Scoped");
+        assert!(err.kind == ErrorKind::CaptiveDependency);
+        assert!(err.message.contains("Repo (Root scope)"));
+        assert!(err.message.contains("Transaction (Scoped scope)"));
+    }
+
     #[test]
     fn error_kind_equality() {
         let err1 = Error::type_mismatch("A");
@@ -1,33 +1,403 @@
 use std::{
     any::{Any, TypeId},
     collections::HashMap,
+    hash::{Hash, Hasher},
+    sync::atomic::{AtomicU64, Ordering},
 };
 
-use crate::error::Error;
+#[cfg(not(feature = "thread-safe"))]
+use std::rc::Weak;
+#[cfg(feature = "thread-safe")]
+use std::sync::Weak;
+#[cfg(feature = "thread-safe")]
+use std::time::Duration;
+
+use crate::dispose::{DisposeError, DisposeHook};
+use crate::error::{Error, ShutdownError};
 use crate::instance::Instance;
+use crate::pending::Pending;
 use crate::provider::Provider;
-use crate::resolve_guard::ResolveGuard;
-use crate::runtime::{Shared, Store};
-use crate::scope::Scope;
+use crate::resolve_guard::{ResolveChain, captive_dependency};
+use crate::runtime::{BoxFuture, Shared, Store};
+use crate::scope::{CaptivePolicy, Scope};
+use crate::table::Table;
+
+#[cfg(feature = "tracing")]
+use tracing::warn;
+
+/// Type-erased provider or instance stored in an injector map.
+#[cfg(not(feature = "thread-safe"))]
+pub(crate) type Entry = Shared<dyn Any>;
+#[cfg(feature = "thread-safe")]
+pub(crate) type Entry = Shared<dyn Any + Send + Sync>;
+
+/// Identifies a registration: the provided type and an optional name.
+///
+/// Unnamed registrations use `name: None`, so [`Injector::provide`] and
+/// [`Injector::provide_named`] never collide for the same type.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct Key {
+    pub(crate) type_id: TypeId,
+    pub(crate) type_name: &'static str,
+    pub(crate) name: Option<String>,
+    /// Id of the multi-binding [`Contribution`] whose instance this key
+    /// identifies, `None` for every other key.
+    pub(crate) contribution: Option<u64>,
+}
+
+impl Key {
+    pub(crate) fn of<T: ?Sized + 'static>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            name: None,
+            contribution: None,
+        }
+    }
+
+    pub(crate) fn named<T: ?Sized + 'static>(name: &str) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            ..Self::of::<T>()
+        }
+    }
+
+    /// Human-readable form used in error messages.
+    pub(crate) fn describe(&self) -> String {
+        match &self.name {
+            Some(name) => format!("{} (named \"{}\")", self.type_name, name),
+            None => self.type_name.to_string(),
+        }
+    }
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_id == other.type_id
+            && self.name == other.name
+            && self.contribution == other.contribution
+    }
+}
+
+impl Eq for Key {}
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.type_id.hash(state);
+        self.name.hash(state);
+        self.contribution.hash(state);
+    }
+}
+
+/// One provider registered through [`Injector::provide_multi`] or
+/// [`Injector::provide_map_entry`].
+///
+/// Each contribution keeps its own instance cache, so several singletons of
+/// the same type can live in one injector. The injector it was registered
+/// with is held weakly: contributions of application modules are also listed
+/// in the root injector, which must not keep the modules alive.
+struct Contribution {
+    /// Distinguishes the instance of this contribution from those of the
+    /// other contributions to the same multi-binding.
+    id: u64,
+    provider: Entry,
+    owner: Weak<InjectorInner>,
+    instance: Store<Option<Entry>>,
+    /// Key of a map multi-binding entry, `None` for plain lists.
+    map_key: Option<Entry>,
+}
+
+impl Contribution {
+    fn map_key<K: 'static>(&self) -> Option<&K> {
+        self.map_key
+            .as_deref()
+            .and_then(|key| key.downcast_ref::<K>())
+    }
+
+    fn is_owned_by(&self, inner: &Shared<InjectorInner>) -> bool {
+        std::ptr::eq(self.owner.as_ptr(), Shared::as_ptr(inner))
+    }
+
+    /// Key identifying the instance of this contribution, distinct for each
+    /// contribution to the multi-binding `key`: it caches a Scoped
+    /// contribution in a scope and, in thread-safe mode, claims the
+    /// construction of a singleton one.
+    fn instance_key(&self, key: &Key) -> Key {
+        Key {
+            contribution: Some(self.id),
+            ..key.clone()
+        }
+    }
+}
+
+/// Source of [`Contribution`] ids, unique across injectors since the
+/// contributions of application modules are also listed in the root one.
+static NEXT_CONTRIBUTION_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Recovers the provider of `key` from its registry entry.
+fn downcast_provider<T>(entry: Entry, key: &Key) -> Result<Shared<Provider<T>>, Error>
+where
+    T: ?Sized + 'static,
+{
+    entry
+        .downcast::<Provider<T>>()
+        .map_err(|_| Error::type_mismatch(&key.describe()))
+}
+
+/// Where the frozen index of an injector finds the provider of a key.
+///
+/// The owning injector is held weakly, like [`Alias`] targets, so that the
+/// index keeps no injector alive; its provider is kept alongside, so that a
+/// frozen resolution needs no other lookup to reach it.
+struct Owner {
+    injector: Weak<InjectorInner>,
+    provider: Entry,
+}
+
+impl Owner {
+    /// The owning injector with the provider, `None` once that injector has
+    /// been dropped.
+    fn locate(&self) -> Option<(Injector, Entry)> {
+        let inner = self.injector.upgrade()?;
+        Some((Injector::from_inner(inner), self.provider.clone()))
+    }
+}
+
+#[cfg(not(feature = "thread-safe"))]
+type FrozenIndex = std::cell::OnceCell<HashMap<Key, Owner>>;
+#[cfg(feature = "thread-safe")]
+type FrozenIndex = std::sync::OnceLock<HashMap<Key, Owner>>;
+
+/// Provider entry published by [`Injector::export_to`].
+///
+/// Resolving the key through the injector holding the alias resolves it in
+/// `target` instead, so the exporting module keeps ownership of the instance.
+/// Aliases to a lazy module carry its [`Activation`], run before `target` is
+/// used.
+///
+/// `target` is held weakly: the exporting module's injector is owned by the
+/// application, and its parent chain leads back to the injector holding the
+/// alias, so a strong reference would keep both alive forever.
+struct Alias {
+    target: Weak<InjectorInner>,
+    activation: Option<Shared<Activation>>,
+}
+
+impl Alias {
+    /// The exporting injector, `None` once it has been dropped.
+    fn target(&self) -> Option<Injector> {
+        self.target.upgrade().map(Injector::from_inner)
+    }
+}
+
+#[cfg(not(feature = "thread-safe"))]
+pub(crate) type ActivateFn = Box<dyn FnOnce() -> Result<(), Error>>;
+#[cfg(feature = "thread-safe")]
+pub(crate) type ActivateFn = Box<dyn FnOnce() -> Result<(), Error> + Send + Sync>;
+
+/// Deferred loading of a lazy module, run by the first resolution that
+/// reaches one of its exports.
+pub(crate) struct Activation {
+    /// Type name of the lazy module, shown in errors.
+    module: &'static str,
+    state: Store<ActivationState>,
+}
+
+enum ActivationState {
+    Pending(ActivateFn),
+    /// The module is loading. Resolutions made while it loads, such as from
+    /// its own `on_init`, go straight to its injector.
+    #[cfg(not(feature = "thread-safe"))]
+    Running,
+    /// The module is loading on `thread`. Resolutions made by that thread
+    /// while it loads, such as from the module's own `on_init`, go straight
+    /// to its injector; other threads wait for `done`.
+    #[cfg(feature = "thread-safe")]
+    Running {
+        thread: std::thread::ThreadId,
+        done: Shared<Pending>,
+    },
+    Done(Result<(), Error>),
+}
+
+impl Activation {
+    pub(crate) fn new(module: &'static str, activate: ActivateFn) -> Self {
+        Self {
+            module,
+            state: Store::new(ActivationState::Pending(activate)),
+        }
+    }
+
+    /// Loads the module on first call and returns the outcome of that load.
+    ///
+    /// In thread-safe mode, a thread arriving while another one loads the
+    /// module blocks until it is loaded, as `resolver` would for a singleton
+    /// under construction.
+    #[cfg_attr(not(feature = "thread-safe"), allow(unused_variables))]
+    pub(crate) fn activate(&self, resolver: &Injector) -> Result<(), Error> {
+        #[cfg(not(feature = "thread-safe"))]
+        let activate = {
+            let mut state = self.state.borrow_mut();
+
+            match std::mem::replace(&mut *state, ActivationState::Running) {
+                ActivationState::Pending(activate) => activate,
+                ActivationState::Running => return Ok(()),
+                ActivationState::Done(outcome) => {
+                    *state = ActivationState::Done(outcome.clone());
+                    return outcome;
+                }
+            }
+        };
+
+        #[cfg(feature = "thread-safe")]
+        let activate = loop {
+            let current = std::thread::current().id();
+            let mut state = self.state.write().unwrap();
+
+            let done = match &*state {
+                ActivationState::Pending(_) => {
+                    let running = ActivationState::Running {
+                        thread: current,
+                        done: Shared::new(Pending::new()),
+                    };
+                    let ActivationState::Pending(activate) =
+                        std::mem::replace(&mut *state, running)
+                    else {
+                        unreachable!("activation state changed under its lock");
+                    };
+                    break activate;
+                }
+                ActivationState::Running { thread, .. } if *thread == current => return Ok(()),
+                ActivationState::Running { done, .. } => done.clone(),
+                ActivationState::Done(outcome) => return outcome.clone(),
+            };
+            drop(state);
+
+            let mut path = resolver.chain.path();
+            path.push(self.module.to_string());
+            done.wait_blocking(path, resolver.chain.held(), resolver.init_timeout())?;
+        };
+
+        let mut run = ActivationRun {
+            activation: self,
+            outcome: None,
+        };
+        let outcome = activate();
+        run.outcome = Some(outcome.clone());
+        outcome
+    }
+}
+
+/// A running module activation.
+///
+/// Dropping it records the outcome and releases the threads waiting for the
+/// module, with a failure when loading panicked before completing.
+struct ActivationRun<'a> {
+    activation: &'a Activation,
+    outcome: Option<Result<(), Error>>,
+}
+
+impl Drop for ActivationRun<'_> {
+    fn drop(&mut self) {
+        let outcome = self
+            .outcome
+            .take()
+            .unwrap_or_else(|| Err(Error::lazy_module_panicked(self.activation.module)));
+
+        #[cfg(not(feature = "thread-safe"))]
+        {
+            *self.activation.state.borrow_mut() = ActivationState::Done(outcome);
+        }
+
+        #[cfg(feature = "thread-safe")]
+        {
+            let mut state = self
+                .activation
+                .state
+                .write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let running = std::mem::replace(&mut *state, ActivationState::Done(outcome.clone()));
+            drop(state);
+
+            if let ActivationState::Running { done, .. } = running {
+                done.complete(outcome);
+            }
+        }
+    }
+}
+
+/// A dispose hook bound to a cached instance.
+struct Disposer {
+    type_name: String,
+    hook: ErasedDispose,
+}
+
+#[cfg(not(feature = "thread-safe"))]
+type SyncDispose = Box<dyn FnOnce() -> Result<(), DisposeError>>;
+#[cfg(feature = "thread-safe")]
+type SyncDispose = Box<dyn FnOnce() -> Result<(), DisposeError> + Send + Sync>;
+
+#[cfg(not(feature = "thread-safe"))]
+type AsyncDisposeFn = Box<dyn FnOnce() -> BoxFuture<'static, Result<(), DisposeError>>>;
+#[cfg(feature = "thread-safe")]
+type AsyncDisposeFn =
+    Box<dyn FnOnce() -> BoxFuture<'static, Result<(), DisposeError>> + Send + Sync>;
+
+enum ErasedDispose {
+    Sync(SyncDispose),
+    Async(AsyncDisposeFn),
+}
 
 pub struct Injector {
     inner: Shared<InjectorInner>,
+    /// Types under construction in the resolution this handle was handed to,
+    /// empty for handles not given to a factory.
+    chain: ResolveChain,
+    /// Innermost scope opened with [`create_scope`](Injector::create_scope)
+    /// this handle resolves in, where Scoped instances are cached.
+    scope: Option<Shared<InjectorInner>>,
 }
 
 struct InjectorInner {
     pub(crate) parent: Option<Shared<InjectorInner>>,
 
-    #[cfg(not(feature = "thread-safe"))]
-    pub(crate) providers: Store<HashMap<TypeId, Shared<dyn Any>>>,
-    #[cfg(not(feature = "thread-safe"))]
-    pub(crate) instances: Store<HashMap<TypeId, Shared<dyn Any>>>,
+    pub(crate) providers: Table,
+    pub(crate) instances: Table,
 
+    /// Async singleton constructions currently in flight.
+    pub(crate) pending: Store<HashMap<Key, Shared<Pending>>>,
+
+    /// Multi-binding contributions, in registration order.
+    multi: Store<HashMap<TypeId, Vec<Shared<Contribution>>>>,
+
+    /// Dispose hooks of the instances cached here, in creation order.
+    disposers: Store<Vec<Disposer>>,
+
+    /// Eager providers registered here and not instantiated yet.
+    eager: Store<Vec<(Key, EagerFn)>>,
+
+    /// Scoped instances, when this injector is a scope. Kept apart from
+    /// `instances` so that cache lookups never reach them.
+    scoped: Table,
+
+    /// Reaction to captive dependencies; only the root injector's is used.
+    captive_policy: Store<CaptivePolicy>,
+
+    /// Longest wait for another thread's construction; only the root
+    /// injector's is used.
     #[cfg(feature = "thread-safe")]
-    pub(crate) providers: Store<HashMap<TypeId, Shared<dyn Any + Send + Sync>>>,
-    #[cfg(feature = "thread-safe")]
-    pub(crate) instances: Store<HashMap<TypeId, Shared<dyn Any + Send + Sync>>>,
+    init_timeout: Store<Option<Duration>>,
+
+    /// Owner of every provider visible from here, set by
+    /// [`Application::freeze`](crate::application::Application::freeze).
+    /// Registrations fail once it is set.
+    index: FrozenIndex,
 }
 
+/// Resolves the provider of a key, discarding the instance.
+type EagerFn = fn(&Injector, &Key) -> Result<(), Error>;
+
 #[cfg(feature = "debug")]
 impl std::fmt::Debug for InjectorInner {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -52,390 +422,3802 @@ impl Clone for Injector {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
+            chain: self.chain.clone(),
+            scope: self.scope.clone(),
         }
     }
 }
 
-impl Injector {
-    pub fn root() -> Self {
-        Self {
-            inner: Shared::new(InjectorInner {
-                parent: None,
-                providers: Store::new(HashMap::new()),
-                instances: Store::new(HashMap::new()),
-            }),
-        }
-    }
-
-    pub fn child(parent: Shared<Injector>) -> Self {
+impl InjectorInner {
+    fn new(parent: Option<Shared<InjectorInner>>) -> Self {
         Self {
-            inner: Shared::new(InjectorInner {
-                parent: Some(parent.inner.clone()),
-                providers: Store::new(HashMap::new()),
-                instances: Store::new(HashMap::new()),
-            }),
+            parent,
+            providers: Table::new(),
+            instances: Table::new(),
+            pending: Store::new(HashMap::new()),
+            multi: Store::new(HashMap::new()),
+            disposers: Store::new(Vec::new()),
+            eager: Store::new(Vec::new()),
+            scoped: Table::new(),
+            captive_policy: Store::new(CaptivePolicy::default()),
+            #[cfg(feature = "thread-safe")]
+            init_timeout: Store::new(None),
+            index: FrozenIndex::new(),
         }
     }
+}
 
-    pub(crate) fn root_injector(&self) -> Injector {
-        let mut current = self.clone();
-
-        while let Some(parent) = &current.inner.parent {
-            current = Injector {
-                inner: parent.clone(),
-            };
-        }
-
-        current
-    }
+/// A short-lived injector returned by [`Injector::create_scope`].
+///
+/// Dereferences to [`Injector`], so services are resolved from it directly.
+/// Scoped services resolved through it are cached here and disposed when it
+/// is dropped; errors raised by their dispose hooks are discarded. Call
+/// [`dispose`](Injector::dispose) or [`dispose_async`](Injector::dispose_async)
+/// first to observe them, or to run async hooks.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ScopedInjector {
+    injector: Injector,
 }
 
-#[cfg(not(feature = "thread-safe"))]
-impl Injector {
-    pub fn try_provide<T>(&self, provider: Provider<T>) -> Result<(), Error>
-    where
-        T: ?Sized + 'static,
-    {
-        match provider.scope {
-            Scope::Root => {
-                let root = self.root_injector();
-                root.store_provider::<T>(provider)
-            }
+impl std::ops::Deref for ScopedInjector {
+    type Target = Injector;
 
-            Scope::Module | Scope::Transient => self.store_provider::<T>(provider),
-        }
+    fn deref(&self) -> &Injector {
+        &self.injector
     }
+}
 
-    pub fn provide<T>(&self, provider: Provider<T>) -> &Self
-    where
-        T: ?Sized + 'static,
-    {
-        self.try_provide::<T>(provider).unwrap();
-        self
+impl Drop for ScopedInjector {
+    fn drop(&mut self) {
+        let _ = self.injector.dispose();
     }
+}
 
-    pub(crate) fn get_provider<T>(&self) -> Option<Shared<dyn Any>>
-    where
-        T: ?Sized + 'static,
-    {
-        let type_id = TypeId::of::<T>();
-
-        let local = self.inner.providers.borrow().get(&type_id).cloned();
-
-        if local.is_some() {
-            return local;
-        }
+/// An application injector that no longer accepts providers, returned by
+/// [`Application::freeze`](crate::application::Application::freeze).
+///
+/// Resolution works as with [`Injector`], except that it starts from the
+/// injector owning the provider, found in the frozen index with a single
+/// lookup: a singleton already built is returned straight from its cache,
+/// anything else is built by that provider.
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct FrozenInjector {
+    injector: Shared<Injector>,
+}
 
-        if let Some(parent) = &self.inner.parent {
-            let parent_injector = Injector {
-                inner: parent.clone(),
-            };
-            return parent_injector.get_provider::<T>();
-        }
+impl FrozenInjector {
+    pub(crate) fn new(injector: Shared<Injector>) -> Self {
+        Self { injector }
+    }
 
-        None
+    /// The frozen injector, to open scopes or resolve multi-bindings.
+    pub fn injector(&self) -> Shared<Injector> {
+        self.injector.clone()
     }
+}
 
-    pub(crate) fn resolve_provider<T>(&self) -> Result<Shared<Provider<T>>, Error>
+#[cfg(not(feature = "thread-safe"))]
+impl FrozenInjector {
+    pub fn try_resolve<T>(&self) -> Result<Shared<T>, Error>
     where
         T: ?Sized + 'static,
     {
-        let type_name = std::any::type_name::<T>();
-
-        let any_provider = self
-            .get_provider::<T>()
-            .ok_or_else(|| Error::service_not_provided(type_name))?;
-
-        let provider = any_provider
-            .downcast::<Provider<T>>()
-            .map_err(|_| Error::type_mismatch(type_name))?;
-
-        Ok(provider)
+        self.resolve_key::<T>(&Key::of::<T>())
     }
 
-    pub(crate) fn resolve_instance<T>(&self) -> Result<Shared<Instance<T>>, Error>
+    pub fn resolve<T>(&self) -> Shared<T>
     where
         T: ?Sized + 'static,
     {
-        let provider_ref = self.resolve_provider::<T>()?;
-
-        Ok(Shared::new((provider_ref.factory)(self)))
+        self.try_resolve::<T>().unwrap()
     }
 
-    pub(crate) fn store_instance<T>(&self, instance: Shared<Instance<T>>)
+    /// Resolves the provider registered for `T` under `name`.
+    pub fn try_resolve_named<T>(&self, name: &str) -> Result<Shared<T>, Error>
     where
         T: ?Sized + 'static,
     {
-        let type_id = TypeId::of::<T>();
-
-        self.inner.instances.borrow_mut().insert(type_id, instance);
+        self.resolve_key::<T>(&Key::named::<T>(name))
     }
 
-    pub(crate) fn store_provider<T>(&self, provider: Provider<T>) -> Result<(), Error>
+    /// Resolves a named provider, panicking on failure.
+    pub fn resolve_named<T>(&self, name: &str) -> Shared<T>
     where
         T: ?Sized + 'static,
     {
-        let type_id = TypeId::of::<T>();
-        let type_name = std::any::type_name::<T>();
-
-        let mut providers = self.inner.providers.borrow_mut();
-        if providers.contains_key(&type_id) {
-            return Err(Error::provider_already_registered(
-                type_name,
-                provider.scope.to_string().as_str(),
-            ));
-        }
-        providers.insert(type_id, Shared::new(provider));
-
-        Ok(())
+        self.try_resolve_named::<T>(name).unwrap()
     }
 
-    pub(crate) fn get_instance<T>(&self) -> Option<Shared<Instance<T>>>
+    fn resolve_key<T>(&self, key: &Key) -> Result<Shared<T>, Error>
     where
         T: ?Sized + 'static,
     {
-        let type_id = TypeId::of::<T>();
+        let (owner, provider) = self
+            .injector
+            .indexed_owner(key)
+            .map_err(|error| error.with_path(|| self.injector.chain.path_to(key)))?;
 
-        let local = self.inner.instances.borrow().get(&type_id).cloned();
-
-        if local.is_some() {
-            return local.and_then(|instance| instance.downcast::<Instance<T>>().ok());
-        }
-
-        if let Some(parent) = &self.inner.parent {
-            let parent_injector = Injector {
-                inner: parent.clone(),
-            };
-            return parent_injector.get_instance::<T>();
+        if let Some(instance) = owner.get_instance::<T>(key) {
+            return Ok(instance.value());
         }
 
-        None
+        self.injector.resolve_located::<T>(key, (owner, provider))
     }
+}
 
+#[cfg(feature = "thread-safe")]
+impl FrozenInjector {
     pub fn try_resolve<T>(&self) -> Result<Shared<T>, Error>
     where
-        T: ?Sized + 'static,
+        T: ?Sized + Send + Sync + 'static,
     {
-        let type_id = TypeId::of::<T>();
-
-        let _guard = ResolveGuard::push(type_id)?;
-
-        if let Some(instance) = self.get_instance::<T>() {
-            return Ok(instance.value());
-        }
-
-        let provider = self.resolve_provider::<T>()?;
-
-        let instance = self.resolve_instance::<T>()?;
-
-        if provider.scope == Scope::Transient {
-            return Ok(instance.value());
-        }
-
-        match provider.scope {
-            Scope::Root => {
-                let root = self.root_injector();
-                root.store_instance::<T>(instance.clone());
-            }
-
-            Scope::Module => {
-                self.store_instance::<T>(instance.clone());
-            }
-
-            Scope::Transient => unreachable!(),
-        }
-
-        Ok(instance.value())
+        self.resolve_key::<T>(&Key::of::<T>())
     }
 
     pub fn resolve<T>(&self) -> Shared<T>
     where
-        T: ?Sized + 'static,
+        T: ?Sized + Send + Sync + 'static,
     {
         self.try_resolve::<T>().unwrap()
     }
 
-    pub fn optional_resolve<T>(&self) -> Option<Shared<T>>
-    where
-        T: ?Sized + 'static,
-    {
-        self.try_resolve::<T>().ok()
-    }
-}
-
-#[cfg(feature = "thread-safe")]
-impl Injector {
-    pub fn try_provide<T>(&self, provider: Provider<T>) -> Result<(), Error>
+    /// Resolves the provider registered for `T` under `name`.
+    pub fn try_resolve_named<T>(&self, name: &str) -> Result<Shared<T>, Error>
     where
         T: ?Sized + Send + Sync + 'static,
     {
-        match provider.scope {
-            Scope::Root => {
-                let root = self.root_injector();
-                root.store_provider::<T>(provider)
-            }
-
-            Scope::Module | Scope::Transient => self.store_provider::<T>(provider),
-        }
+        self.resolve_key::<T>(&Key::named::<T>(name))
     }
 
-    pub fn provide<T>(&self, provider: Provider<T>) -> &Self
+    /// Resolves a named provider, panicking on failure.
+    pub fn resolve_named<T>(&self, name: &str) -> Shared<T>
     where
         T: ?Sized + Send + Sync + 'static,
     {
-        self.try_provide::<T>(provider).unwrap();
-        self
+        self.try_resolve_named::<T>(name).unwrap()
     }
 
-    pub(crate) fn get_provider<T>(&self) -> Option<Shared<dyn Any + Send + Sync>>
+    fn resolve_key<T>(&self, key: &Key) -> Result<Shared<T>, Error>
     where
         T: ?Sized + Send + Sync + 'static,
     {
-        let type_id = TypeId::of::<T>();
+        let (owner, provider) = self
+            .injector
+            .indexed_owner(key)
+            .map_err(|error| error.with_path(|| self.injector.chain.path_to(key)))?;
 
-        let local = self.inner.providers.read().unwrap().get(&type_id).cloned();
-
-        if local.is_some() {
-            return local;
+        if let Some(instance) = owner.get_instance::<T>(key) {
+            return Ok(instance.value());
         }
 
-        if let Some(parent) = &self.inner.parent {
-            let parent_injector = Injector {
-                inner: parent.clone(),
-            };
-            return parent_injector.get_provider::<T>();
-        }
+        self.injector.resolve_located::<T>(key, (owner, provider))
+    }
+}
 
-        None
+impl Injector {
+    pub fn root() -> Self {
+        Self::from_inner(Shared::new(InjectorInner::new(None)))
     }
 
-    pub(crate) fn resolve_provider<T>(&self) -> Result<Shared<Provider<T>>, Error>
-    where
-        T: ?Sized + Send + Sync + 'static,
-    {
-        let type_name = std::any::type_name::<T>();
+    pub fn child(parent: Shared<Injector>) -> Self {
+        Self::from_inner(Shared::new(InjectorInner::new(Some(parent.inner.clone()))))
+    }
 
-        let any_provider = self
-            .get_provider::<T>()
-            .ok_or_else(|| Error::service_not_provided(type_name))?;
+    fn from_inner(inner: Shared<InjectorInner>) -> Self {
+        Self {
+            inner,
+            chain: ResolveChain::default(),
+            scope: None,
+        }
+    }
 
-        let provider = any_provider
-            .downcast::<Provider<T>>()
-            .map_err(|_| Error::type_mismatch(type_name))?;
+    /// Opens a scope, such as one HTTP request or one unit of work.
+    ///
+    /// The returned injector is a child of this one. Services registered with
+    /// [`Scope::Scoped`] and resolved through it, directly or as dependencies
+    /// of other Scoped or Transient services, are created once and cached in
+    /// the scope. They are disposed when the scope is dropped.
+    ///
+    /// A Root or Module singleton cannot depend on a Scoped service, since it
+    /// would keep the instance alive after its scope ends; such a resolution
+    /// fails with
+    /// [`ErrorKind::CaptiveDependency`](crate::error::ErrorKind::CaptiveDependency).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Provider, Shared};
+    ///
+    /// struct Transaction;
+    ///
+    /// let injector = Injector::root();
+    /// injector.provide::<Transaction>(Provider::scoped(|_| Shared::new(Transaction)));
+    ///
+    /// let first = injector.create_scope();
+    /// let second = injector.create_scope();
+    ///
+    /// assert!(Shared::ptr_eq(
+    ///     &first.resolve::<Transaction>(),
+    ///     &first.resolve::<Transaction>()
+    /// ));
+    /// assert!(!Shared::ptr_eq(
+    ///     &first.resolve::<Transaction>(),
+    ///     &second.resolve::<Transaction>()
+    /// ));
+    /// ```
+    pub fn create_scope(&self) -> ScopedInjector {
+        let inner = Shared::new(InjectorInner::new(Some(self.inner.clone())));
 
-        Ok(provider)
+        ScopedInjector {
+            injector: Injector {
+                inner: inner.clone(),
+                chain: ResolveChain::default(),
+                scope: Some(inner),
+            },
+        }
     }
 
-    pub(crate) fn resolve_instance<T>(&self) -> Result<Shared<Instance<T>>, Error>
-    where
-        T: ?Sized + Send + Sync + 'static,
-    {
-        let provider_ref = self.resolve_provider::<T>()?;
+    /// Sets how resolutions through every injector of this tree react to a
+    /// service depending on a shorter-lived one.
+    ///
+    /// The policy is kept by the root injector and defaults to
+    /// [`CaptivePolicy::Deny`]. See [`Scope::outlives`] for the order of
+    /// scopes.
+    pub fn set_captive_policy(&self, policy: CaptivePolicy) {
+        let root = self.root_injector();
 
-        Ok(Shared::new((provider_ref.factory)(self)))
+        #[cfg(not(feature = "thread-safe"))]
+        root.inner.captive_policy.replace(policy);
+        #[cfg(feature = "thread-safe")]
+        {
+            *root.inner.captive_policy.write().unwrap() = policy;
+        }
     }
 
-    pub(crate) fn store_instance<T>(&self, instance: Shared<Instance<T>>)
-    where
-        T: ?Sized + Send + Sync + 'static,
-    {
-        let type_id = TypeId::of::<T>();
+    /// Returns the captive dependency policy of this injector tree.
+    pub fn captive_policy(&self) -> CaptivePolicy {
+        let root = self.root_injector();
 
-        self.inner
-            .instances
-            .write()
-            .unwrap()
-            .insert(type_id, instance);
+        #[cfg(not(feature = "thread-safe"))]
+        let policy = *root.inner.captive_policy.borrow();
+        #[cfg(feature = "thread-safe")]
+        let policy = *root.inner.captive_policy.read().unwrap();
+
+        policy
     }
 
-    pub(crate) fn store_provider<T>(&self, provider: Provider<T>) -> Result<(), Error>
-    where
-        T: ?Sized + Send + Sync + 'static,
-    {
-        let type_id = TypeId::of::<T>();
-        let type_name = std::any::type_name::<T>();
+    /// Sets how long resolutions through every injector of this tree wait
+    /// for another thread to finish building a singleton, after which they
+    /// fail with [`ErrorKind::InitTimeout`](crate::error::ErrorKind::InitTimeout).
+    ///
+    /// Threads waiting for each other are detected and fail with a circular
+    /// dependency right away; the timeout bounds the other waits, such as on
+    /// a factory blocked outside of the injector. The timeout is kept by the
+    /// root injector and defaults to `None`, waiting as long as it takes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use sadi::Injector;
+    ///
+    /// let injector = Injector::root();
+    /// injector.set_init_timeout(Some(Duration::from_secs(5)));
+    ///
+    /// assert_eq!(injector.init_timeout(), Some(Duration::from_secs(5)));
+    /// ```
+    #[cfg(feature = "thread-safe")]
+    pub fn set_init_timeout(&self, timeout: Option<Duration>) {
+        *self.root_injector().inner.init_timeout.write().unwrap() = timeout;
+    }
 
-        let mut providers = self.inner.providers.write().unwrap();
-        if providers.contains_key(&type_id) {
-            return Err(Error::provider_already_registered(
-                type_name,
-                provider.scope.to_string().as_str(),
-            ));
+    /// Returns the init timeout of this injector tree.
+    #[cfg(feature = "thread-safe")]
+    pub fn init_timeout(&self) -> Option<Duration> {
+        *self.root_injector().inner.init_timeout.read().unwrap()
+    }
+
+    /// Applies the captive policy to `key`, provided with `scope`, when it is
+    /// resolved while `captor` is being built.
+    fn check_captive(
+        &self,
+        captor: Option<(Key, Scope)>,
+        key: &Key,
+        scope: Scope,
+    ) -> Result<(), Error> {
+        let Some(captor) = captor else {
+            return Ok(());
+        };
+
+        match self.captive_policy() {
+            CaptivePolicy::Deny => Err(captive_dependency(&captor, key, scope)),
+            CaptivePolicy::Warn => {
+                #[cfg(feature = "tracing")]
+                warn!(
+                    "Captive dependency: {} ({} scope) depends on {} ({} scope)",
+                    captor.0.describe(),
+                    captor.1,
+                    key.describe(),
+                    scope
+                );
+                Ok(())
+            }
+            CaptivePolicy::Allow => Ok(()),
+        }
+    }
+
+    /// The injector a factory of the given scope registered in this one runs
+    /// with, when resolved through `resolver`.
+    ///
+    /// Root factories always run with the root injector, so a Root singleton
+    /// is wired the same way whichever module resolves it first.
+    fn factory_injector(&self, resolver: &Injector, scope: Scope) -> Injector {
+        match scope {
+            Scope::Root => self.root_injector().in_scope_of(resolver, scope),
+            Scope::Module | Scope::Transient | Scope::Scoped => self.in_scope_of(resolver, scope),
         }
-        providers.insert(type_id, Shared::new(provider));
+    }
 
-        Ok(())
+    /// The scope Scoped services resolved through this handle are cached in.
+    fn active_scope(&self) -> Option<Injector> {
+        self.scope.clone().map(Self::from_inner)
     }
 
-    pub(crate) fn get_instance<T>(&self) -> Option<Shared<Instance<T>>>
-    where
-        T: ?Sized + Send + Sync + 'static,
-    {
-        let type_id = TypeId::of::<T>();
+    /// This injector continuing the resolution of `other`: with its chain of
+    /// types under construction, and in its active scope for factories whose
+    /// instances do not outlive it. Singletons get no scope.
+    fn in_scope_of(&self, other: &Injector, scope: Scope) -> Injector {
+        let scope = match scope {
+            Scope::Root | Scope::Module => None,
+            Scope::Transient | Scope::Scoped => other.scope.clone(),
+        };
+
+        Injector {
+            inner: self.inner.clone(),
+            chain: other.chain.clone(),
+            scope,
+        }
+    }
+
+    pub(crate) fn parent_injector(&self) -> Option<Injector> {
+        self.inner.parent.clone().map(Self::from_inner)
+    }
+
+    pub(crate) fn root_injector(&self) -> Injector {
+        let mut current = self.clone();
 
-        let local = self.inner.instances.read().unwrap().get(&type_id).cloned();
+        while let Some(parent) = current.parent_injector() {
+            current = parent;
+        }
+
+        current
+    }
+
+    /// Looks `key` up in this injector, then in each ancestor.
+    pub(crate) fn get_provider(&self, key: &Key) -> Option<Entry> {
+        let local = self.inner.providers.get(key);
 
         if local.is_some() {
-            return local.and_then(|instance| instance.downcast::<Instance<T>>().ok());
+            return local;
         }
 
-        if let Some(parent) = &self.inner.parent {
-            let parent_injector = Injector {
-                inner: parent.clone(),
+        self.parent_injector()
+            .and_then(|parent| parent.get_provider(key))
+    }
+
+    /// Looks a Scoped instance up in this scope.
+    fn get_scoped(&self, key: &Key) -> Option<Entry> {
+        self.inner.scoped.get(key)
+    }
+
+    /// Looks a singleton up in this injector's cache.
+    ///
+    /// Ancestors are not searched: a singleton is only ever cached in the
+    /// injector that registered its provider.
+    pub(crate) fn get_cached(&self, key: &Key) -> Option<Entry> {
+        self.inner.instances.get(key)
+    }
+
+    fn has_local_provider(&self, key: &Key) -> bool {
+        self.inner.providers.contains_key(key)
+    }
+
+    /// Looks an alias for `key` up in this injector only.
+    ///
+    /// Ancestors are not searched: from an exporting module's injector, they
+    /// can hold the alias leading back to it.
+    fn get_alias(&self, key: &Key) -> Option<Shared<Alias>> {
+        self.inner
+            .providers
+            .get(key)
+            .and_then(|entry| entry.downcast::<Alias>().ok())
+    }
+
+    /// Returns the injector that owns the provider of `key`, with that
+    /// provider, following the aliases published by module exports and
+    /// activating lazy modules on the way.
+    fn follow_aliases(&self, key: &Key) -> Result<(Injector, Entry), Error> {
+        let mut current = self.registrant(key);
+
+        while let Some((_, entry)) = &current
+            && let Ok(alias) = entry.clone().downcast::<Alias>()
+        {
+            if let Some(activation) = &alias.activation {
+                activation.activate(self)?;
+            }
+            let target = alias
+                .target()
+                .ok_or_else(|| Error::service_not_provided(&key.describe()))?;
+
+            current = match target.inner.providers.get(key) {
+                Some(entry) => Some((target, entry)),
+                None => target.registrant(key),
             };
-            return parent_injector.get_instance::<T>();
         }
 
-        None
+        current.ok_or_else(|| Error::service_not_provided(&key.describe()))
     }
 
-    pub fn try_resolve<T>(&self) -> Result<Shared<T>, Error>
-    where
-        T: ?Sized + Send + Sync + 'static,
-    {
-        let type_id = TypeId::of::<T>();
+    /// The injector, among this one and its ancestors, where the provider of
+    /// `key` is registered, with that provider.
+    fn registrant(&self, key: &Key) -> Option<(Injector, Entry)> {
+        let mut current = &self.inner;
 
-        let _guard = ResolveGuard::push(type_id)?;
+        loop {
+            if let Some(entry) = current.providers.get(key) {
+                return Some((Self::from_inner(current.clone()), entry));
+            }
+            current = current.parent.as_ref()?;
+        }
+    }
 
-        if let Some(instance) = self.get_instance::<T>() {
-            return Ok(instance.value());
+    /// Like [`follow_aliases`](Injector::follow_aliases), without activating
+    /// lazy modules.
+    fn owner_of(&self, key: &Key) -> Injector {
+        let registrant = |injector: &Injector| injector.registrant(key).map(|(owner, _)| owner);
+        let mut current = registrant(self).unwrap_or_else(|| self.clone());
+
+        while let Some(target) = current.get_alias(key).and_then(|alias| alias.target()) {
+            current = target;
         }
 
-        let provider = self.resolve_provider::<T>()?;
+        registrant(&current).unwrap_or(current)
+    }
 
-        let instance = self.resolve_instance::<T>()?;
+    /// Returns the injector that owns the provider of `key`, with that
+    /// provider, from the frozen index when there is one, otherwise as
+    /// [`follow_aliases`](Injector::follow_aliases) does.
+    fn locate(&self, key: &Key) -> Result<(Injector, Entry), Error> {
+        if !self.is_frozen() {
+            return self.follow_aliases(key);
+        }
 
-        if provider.scope == Scope::Transient {
-            return Ok(instance.value());
+        self.indexed_owner(key)
+    }
+
+    /// The injector the frozen index records as the owner of `key`, with
+    /// its provider.
+    fn indexed_owner(&self, key: &Key) -> Result<(Injector, Entry), Error> {
+        self.inner
+            .index
+            .get()
+            .and_then(|index| index.get(key))
+            .and_then(Owner::locate)
+            .ok_or_else(|| Error::service_not_provided(&key.describe()))
+    }
+
+    /// Records the owner of every provider visible from this injector, and
+    /// rejects registrations here from then on.
+    ///
+    /// Lazy modules must be loaded beforehand: the index does not follow
+    /// their aliases.
+    pub(crate) fn freeze(&self) {
+        use std::collections::hash_map::Entry as Slot;
+
+        if self.is_frozen() {
+            return;
+        }
+
+        let mut index = HashMap::new();
+        let mut current = Some(self.clone());
+
+        while let Some(injector) = current {
+            for key in injector.inner.providers.keys_where(|_| true) {
+                if let Slot::Vacant(slot) = index.entry(key) {
+                    let owner = self.owner_of(slot.key());
+                    if let Some(provider) = owner.inner.providers.get(slot.key()) {
+                        slot.insert(Owner {
+                            injector: Shared::downgrade(&owner.inner),
+                            provider,
+                        });
+                    }
+                }
+            }
+            current = injector.parent_injector();
+        }
+
+        let _ = self.inner.index.set(index);
+    }
+
+    fn is_frozen(&self) -> bool {
+        self.inner.index.get().is_some()
+    }
+
+    /// Publishes the provider of `key` registered in this injector to `target`.
+    ///
+    /// Used by [`Application`](crate::application::Application) to make a
+    /// module's exports visible in its importer's injector. Providers stored
+    /// in the root injector are visible everywhere and need no alias.
+    pub(crate) fn export_to(&self, target: &Injector, key: &Key) -> Result<(), Error> {
+        self.check_export(key)?;
+
+        if !self.has_local_provider(key) {
+            return Ok(());
+        }
+
+        self.insert_alias(target, key, None)
+    }
+
+    /// Fails unless the provider of `key` is registered in this injector, or
+    /// in the root injector where it needs no alias. Aliases published to the
+    /// root injector do not count: they may lead back here.
+    pub(crate) fn check_export(&self, key: &Key) -> Result<(), Error> {
+        let in_root = self
+            .root_injector()
+            .inner
+            .providers
+            .get(key)
+            .is_some_and(|entry| !entry.is::<Alias>());
+
+        if self.has_local_provider(key) || in_root {
+            return Ok(());
+        }
+
+        Err(Error::export_not_provided(&key.describe()))
+    }
+
+    /// Publishes `key` to `target` on behalf of a lazy module that has not
+    /// registered its providers yet.
+    pub(crate) fn export_lazy_to(
+        &self,
+        target: &Injector,
+        key: &Key,
+        activation: &Shared<Activation>,
+    ) -> Result<(), Error> {
+        self.insert_alias(target, key, Some(activation.clone()))
+    }
+
+    fn insert_alias(
+        &self,
+        target: &Injector,
+        key: &Key,
+        activation: Option<Shared<Activation>>,
+    ) -> Result<(), Error> {
+        let existing = target.inner.providers.get(key);
+
+        // A shared module reached through several imports exports the same
+        // provider more than once.
+        if let Some(alias) = existing.and_then(|entry| entry.downcast::<Alias>().ok())
+            && let Some(exporter) = alias.target()
+            && Shared::ptr_eq(&exporter.owner_of(key).inner, &self.owner_of(key).inner)
+        {
+            return Ok(());
+        }
+
+        let alias = Alias {
+            target: Shared::downgrade(&self.inner),
+            activation,
+        };
+        target.insert_provider(key.clone(), Shared::new(alias), "Export")
+    }
+
+    /// Publishes every provider registered in this injector to the root
+    /// injector, making it visible from every injector of the application.
+    ///
+    /// Used for global modules. Aliases from the module's own imports are not
+    /// published.
+    pub(crate) fn export_all_to_root(&self) -> Result<(), Error> {
+        let keys = self
+            .inner
+            .providers
+            .keys_where(|entry| !entry.is::<Alias>());
+
+        let root = self.root_injector();
+        for key in &keys {
+            self.export_to(&root, key)?;
+        }
+        Ok(())
+    }
+
+    fn insert_provider(&self, key: Key, entry: Entry, scope: &str) -> Result<(), Error> {
+        let description = key.describe();
+
+        if self.is_frozen() {
+            return Err(Error::frozen(&description));
+        }
+
+        if !self.inner.providers.insert_new(key, entry) {
+            return Err(Error::provider_already_registered(&description, scope));
+        }
+
+        Ok(())
+    }
+
+    fn insert_scoped(&self, key: &Key, entry: Entry) {
+        self.inner.scoped.insert(key.clone(), entry);
+    }
+
+    fn insert_instance(&self, key: &Key, entry: Entry) {
+        self.inner.instances.insert(key.clone(), entry);
+    }
+
+    fn push_contribution(
+        &self,
+        type_id: TypeId,
+        type_name: &str,
+        provider: Entry,
+        map_key: Option<Entry>,
+    ) -> Result<(), Error> {
+        if self.is_frozen() {
+            return Err(Error::frozen(type_name));
+        }
+
+        let contribution = Shared::new(Contribution {
+            id: NEXT_CONTRIBUTION_ID.fetch_add(1, Ordering::Relaxed),
+            provider,
+            owner: Shared::downgrade(&self.inner),
+            instance: Store::new(None),
+            map_key,
+        });
+
+        #[cfg(not(feature = "thread-safe"))]
+        let mut multi = self.inner.multi.borrow_mut();
+        #[cfg(feature = "thread-safe")]
+        let mut multi = self.inner.multi.write().unwrap();
+
+        multi.entry(type_id).or_default().push(contribution);
+        Ok(())
+    }
+
+    /// Collects contributions for `type_id` with the injector holding each,
+    /// ancestors first, each injector in registration order.
+    fn contributions(&self, type_id: TypeId) -> Vec<(Injector, Shared<Contribution>)> {
+        let mut collected = self
+            .parent_injector()
+            .map(|parent| parent.contributions(type_id))
+            .unwrap_or_default();
+
+        #[cfg(not(feature = "thread-safe"))]
+        let multi = self.inner.multi.borrow();
+        #[cfg(feature = "thread-safe")]
+        let multi = self.inner.multi.read().unwrap();
+
+        for contribution in multi.get(&type_id).into_iter().flatten() {
+            let listed = collected
+                .iter()
+                .any(|(_, seen)| Shared::ptr_eq(seen, contribution));
+
+            if let Some(owner) = contribution.owner.upgrade()
+                && !listed
+            {
+                collected.push((Self::from_inner(owner), contribution.clone()));
+            }
+        }
+
+        collected
+    }
+
+    /// Lists the contributions registered with this module injector in the
+    /// root injector as well, so that they are collected from every module of
+    /// the application and not only from this one and its imports.
+    pub(crate) fn publish_contributions(&self) {
+        let root = self.root_injector();
+        if Shared::ptr_eq(&root.inner, &self.inner) {
+            return;
+        }
+
+        #[cfg(not(feature = "thread-safe"))]
+        let local = self.inner.multi.borrow().clone();
+        #[cfg(feature = "thread-safe")]
+        let local = self.inner.multi.read().unwrap().clone();
+
+        #[cfg(not(feature = "thread-safe"))]
+        let mut multi = root.inner.multi.borrow_mut();
+        #[cfg(feature = "thread-safe")]
+        let mut multi = root.inner.multi.write().unwrap();
+
+        for (type_id, contributions) in local {
+            multi.entry(type_id).or_default().extend(contributions);
+        }
+    }
+
+    fn push_eager(&self, key: Key, resolve: EagerFn) {
+        #[cfg(not(feature = "thread-safe"))]
+        self.inner.eager.borrow_mut().push((key, resolve));
+        #[cfg(feature = "thread-safe")]
+        self.inner.eager.write().unwrap().push((key, resolve));
+    }
+
+    /// Instantiates the [eager](crate::provider::Provider::eager) providers
+    /// registered through this injector, in registration order, collecting
+    /// failures into `errors`.
+    pub(crate) fn instantiate_eager_into(&self, errors: &mut Vec<Error>) {
+        #[cfg(not(feature = "thread-safe"))]
+        let eager = std::mem::take(&mut *self.inner.eager.borrow_mut());
+        #[cfg(feature = "thread-safe")]
+        let eager = std::mem::take(&mut *self.inner.eager.write().unwrap());
+
+        for (key, resolve) in eager {
+            if let Err(error) = resolve(self, &key) {
+                errors.push(error);
+            }
+        }
+    }
+
+    fn push_disposer(&self, disposer: Disposer) {
+        #[cfg(not(feature = "thread-safe"))]
+        self.inner.disposers.borrow_mut().push(disposer);
+        #[cfg(feature = "thread-safe")]
+        self.inner.disposers.write().unwrap().push(disposer);
+    }
+
+    /// Releases the instances cached in this injector and returns their
+    /// dispose hooks, most recently created first.
+    fn take_disposers(&self) -> Vec<Disposer> {
+        #[cfg(not(feature = "thread-safe"))]
+        let mut disposers = std::mem::take(&mut *self.inner.disposers.borrow_mut());
+        #[cfg(feature = "thread-safe")]
+        let mut disposers = std::mem::take(&mut *self.inner.disposers.write().unwrap());
+
+        #[cfg(not(feature = "thread-safe"))]
+        {
+            self.inner.instances.clear();
+            self.inner.scoped.clear();
+            for contribution in self.inner.multi.borrow().values().flatten() {
+                if contribution.is_owned_by(&self.inner) {
+                    contribution.instance.replace(None);
+                }
+            }
+        }
+        #[cfg(feature = "thread-safe")]
+        {
+            self.inner.instances.clear();
+            self.inner.scoped.clear();
+            for contribution in self.inner.multi.read().unwrap().values().flatten() {
+                if contribution.is_owned_by(&self.inner) {
+                    contribution.instance.write().unwrap().take();
+                }
+            }
+        }
+
+        disposers.reverse();
+        disposers
+    }
+
+    /// Disposes the singletons cached in this injector, most recently created
+    /// first.
+    ///
+    /// Only instances built by providers registered with
+    /// [`Provider::with_dispose`] or [`Provider::with_async_dispose`] have a
+    /// hook; every cached instance is released from the cache either way. All
+    /// hooks run even when some fail, and the failures are returned together.
+    /// Async hooks cannot run here and are reported as errors; use
+    /// [`dispose_async`](Injector::dispose_async) for them. Ancestor and child
+    /// injectors are not affected.
+    pub fn dispose(&self) -> Result<(), ShutdownError> {
+        let mut errors = Vec::new();
+        self.dispose_into(&mut errors);
+
+        ShutdownError::check(errors)
+    }
+
+    /// Disposes the singletons cached in this injector, awaiting async hooks.
+    ///
+    /// See [`dispose`](Injector::dispose).
+    pub async fn dispose_async(&self) -> Result<(), ShutdownError> {
+        let mut errors = Vec::new();
+        self.dispose_async_into(&mut errors).await;
+
+        ShutdownError::check(errors)
+    }
+
+    pub(crate) fn dispose_into(&self, errors: &mut Vec<Error>) {
+        for disposer in self.take_disposers() {
+            let result = match disposer.hook {
+                ErasedDispose::Sync(dispose) => dispose(),
+                ErasedDispose::Async(_) => {
+                    errors.push(Error::async_dispose(&disposer.type_name));
+                    continue;
+                }
+            };
+
+            if let Err(source) = result {
+                errors.push(Error::dispose_failed(&disposer.type_name, source));
+            }
+        }
+    }
+
+    pub(crate) async fn dispose_async_into(&self, errors: &mut Vec<Error>) {
+        for disposer in self.take_disposers() {
+            let result = match disposer.hook {
+                ErasedDispose::Sync(dispose) => dispose(),
+                ErasedDispose::Async(dispose) => dispose().await,
+            };
+
+            if let Err(source) = result {
+                errors.push(Error::dispose_failed(&disposer.type_name, source));
+            }
+        }
+    }
+
+    /// Claims the construction of `key` in this injector.
+    ///
+    /// Used by async resolutions and, in thread-safe mode, by synchronous
+    /// ones. Checked under the `pending` lock so that a construction finishing
+    /// between the caller's cache lookup and this call is never repeated.
+    fn begin_init(&self, key: &Key) -> Init {
+        #[cfg(not(feature = "thread-safe"))]
+        let mut pending = self.inner.pending.borrow_mut();
+        #[cfg(feature = "thread-safe")]
+        let mut pending = self.inner.pending.write().unwrap();
+
+        if let Some(existing) = pending.get(key) {
+            return Init::Wait(existing.clone());
+        }
+
+        if self.inner.instances.contains_key(key) || self.inner.scoped.contains_key(key) {
+            return Init::Cached;
         }
 
+        let entry = Shared::new(Pending::new());
+        pending.insert(key.clone(), entry.clone());
+
+        Init::Owned(InitTicket {
+            owner: self.clone(),
+            key: key.clone(),
+            pending: entry,
+            outcome: None,
+        })
+    }
+}
+
+enum Init {
+    /// The caller runs the factory and must finish the ticket.
+    Owned(InitTicket),
+    /// Another resolution is building the instance.
+    Wait(Shared<Pending>),
+    /// The instance was stored since the caller last looked.
+    Cached,
+}
+
+/// Ownership of an in-flight singleton construction.
+///
+/// Dropping the ticket releases waiting resolutions: with the recorded outcome
+/// when the factory finished, or with a retry signal when the future was
+/// cancelled or the factory panicked before completing.
+struct InitTicket {
+    owner: Injector,
+    key: Key,
+    pending: Shared<Pending>,
+    outcome: Option<Result<(), Error>>,
+}
+
+impl Drop for InitTicket {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "thread-safe"))]
+        self.owner.inner.pending.borrow_mut().remove(&self.key);
+        #[cfg(feature = "thread-safe")]
+        self.owner.inner.pending.write().unwrap().remove(&self.key);
+
+        self.pending.complete(self.outcome.take().unwrap_or(Ok(())));
+    }
+}
+
+#[cfg(not(feature = "thread-safe"))]
+impl Injector {
+    pub fn try_provide<T>(&self, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + 'static,
+    {
+        self.provide_key::<T>(Key::of::<T>(), provider)
+    }
+
+    pub fn provide<T>(&self, provider: Provider<T>) -> &Self
+    where
+        T: ?Sized + 'static,
+    {
+        self.try_provide::<T>(provider).unwrap();
+        self
+    }
+
+    /// Registers a provider for `T` under `name`.
+    ///
+    /// Named registrations live next to the unnamed one, so several bindings
+    /// of the same type can coexist. Scope rules are the same as for
+    /// [`try_provide`](Injector::try_provide): Root providers are stored in the
+    /// root injector, all others in this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Provider, Shared};
+    ///
+    /// let injector = Injector::root();
+    /// injector
+    ///     .try_provide_named::<String>("primary", Provider::root(|_| Shared::new("db-1".to_string())))
+    ///     .unwrap();
+    /// injector
+    ///     .try_provide_named::<String>("replica", Provider::root(|_| Shared::new("db-2".to_string())))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(*injector.resolve_named::<String>("replica"), "db-2");
+    /// ```
+    pub fn try_provide_named<T>(&self, name: &str, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + 'static,
+    {
+        self.provide_key::<T>(Key::named::<T>(name), provider)
+    }
+
+    /// Registers a named provider, panicking on failure.
+    ///
+    /// See [`try_provide_named`](Injector::try_provide_named).
+    pub fn provide_named<T>(&self, name: &str, provider: Provider<T>) -> &Self
+    where
+        T: ?Sized + 'static,
+    {
+        self.try_provide_named::<T>(name, provider).unwrap();
+        self
+    }
+
+    fn provide_key<T>(&self, key: Key, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + 'static,
+    {
+        let eager = (provider.eager && provider.scope.is_singleton()).then(|| key.clone());
+
         match provider.scope {
             Scope::Root => {
                 let root = self.root_injector();
-                root.store_instance::<T>(instance.clone());
+                root.store_provider::<T>(key, provider)?;
             }
 
-            Scope::Module => {
-                self.store_instance::<T>(instance.clone());
+            Scope::Module | Scope::Transient | Scope::Scoped => {
+                self.store_provider::<T>(key, provider)?
             }
+        }
 
-            Scope::Transient => unreachable!(),
+        if let Some(key) = eager {
+            self.push_eager(key, Self::resolve_eager::<T>);
         }
+        Ok(())
+    }
 
-        Ok(instance.value())
+    fn resolve_eager<T>(&self, key: &Key) -> Result<(), Error>
+    where
+        T: ?Sized + 'static,
+    {
+        self.resolve_key::<T>(key).map(|_| ())
     }
 
-    pub fn resolve<T>(&self) -> Shared<T>
+    pub(crate) fn resolve_provider<T>(&self, key: &Key) -> Result<Shared<Provider<T>>, Error>
     where
-        T: ?Sized + Send + Sync + 'static,
+        T: ?Sized + 'static,
     {
-        self.try_resolve::<T>().unwrap()
+        let any_provider = self
+            .get_provider(key)
+            .ok_or_else(|| Error::service_not_provided(&key.describe()))?;
+
+        downcast_provider::<T>(any_provider, key)
     }
 
-    pub fn optional_resolve<T>(&self) -> Option<Shared<T>>
+    pub(crate) fn store_instance<T>(&self, key: &Key, instance: Shared<Instance<T>>)
     where
-        T: ?Sized + Send + Sync + 'static,
+        T: ?Sized + 'static,
     {
-        self.try_resolve::<T>().ok()
+        self.insert_instance(key, instance);
+    }
+
+    /// Records the dispose hook of `provider` for an instance cached here.
+    fn track_dispose<T>(&self, key: &Key, provider: &Provider<T>, instance: &Shared<Instance<T>>)
+    where
+        T: ?Sized + 'static,
+    {
+        let Some(hook) = provider.dispose else {
+            return;
+        };
+
+        let value = instance.value();
+        let hook = match hook {
+            DisposeHook::Sync(dispose) => ErasedDispose::Sync(Box::new(move || dispose(&value))),
+            DisposeHook::Async(dispose) => ErasedDispose::Async(Box::new(move || dispose(value))),
+        };
+
+        self.push_disposer(Disposer {
+            type_name: key.describe(),
+            hook,
+        });
+    }
+
+    pub(crate) fn store_provider<T>(&self, key: Key, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + 'static,
+    {
+        let scope = provider.scope.to_string();
+        self.insert_provider(key, Shared::new(provider), &scope)
+    }
+
+    pub(crate) fn get_instance<T>(&self, key: &Key) -> Option<Shared<Instance<T>>>
+    where
+        T: ?Sized + 'static,
+    {
+        self.get_cached(key)
+            .and_then(|instance| instance.downcast::<Instance<T>>().ok())
+    }
+
+    fn get_scoped_instance<T>(&self, key: &Key) -> Option<Shared<Instance<T>>>
+    where
+        T: ?Sized + 'static,
+    {
+        self.get_scoped(key)
+            .and_then(|instance| instance.downcast::<Instance<T>>().ok())
+    }
+
+    pub fn try_resolve<T>(&self) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        self.resolve_key::<T>(&Key::of::<T>())
+    }
+
+    pub fn resolve<T>(&self) -> Shared<T>
+    where
+        T: ?Sized + 'static,
+    {
+        self.try_resolve::<T>().unwrap()
+    }
+
+    pub fn optional_resolve<T>(&self) -> Option<Shared<T>>
+    where
+        T: ?Sized + 'static,
+    {
+        self.try_resolve::<T>().ok()
+    }
+
+    /// Resolves the provider registered for `T` under `name`.
+    ///
+    /// The lookup walks the parent chain exactly like
+    /// [`try_resolve`](Injector::try_resolve), and the instance is cached
+    /// according to the provider's scope.
+    pub fn try_resolve_named<T>(&self, name: &str) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        self.resolve_key::<T>(&Key::named::<T>(name))
+    }
+
+    /// Resolves a named provider, panicking on failure.
+    ///
+    /// See [`try_resolve_named`](Injector::try_resolve_named).
+    pub fn resolve_named<T>(&self, name: &str) -> Shared<T>
+    where
+        T: ?Sized + 'static,
+    {
+        self.try_resolve_named::<T>(name).unwrap()
+    }
+
+    /// Resolves a named provider, returning `None` on failure.
+    pub fn optional_resolve_named<T>(&self, name: &str) -> Option<Shared<T>>
+    where
+        T: ?Sized + 'static,
+    {
+        self.try_resolve_named::<T>(name).ok()
+    }
+
+    fn resolve_key<T>(&self, key: &Key) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        self.chain.check(key)?;
+
+        let located = self
+            .locate(key)
+            .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+        self.resolve_located::<T>(key, located)
+    }
+
+    /// Resolves `key` with the provider `entry` registered in `injector`.
+    fn resolve_located<T>(
+        &self,
+        key: &Key,
+        (injector, entry): (Injector, Entry),
+    ) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        let provider = downcast_provider::<T>(entry, key)
+            .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+        injector
+            .check_captive(self.chain.captor(provider.scope), key, provider.scope)
+            .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+        if provider.scope.is_singleton()
+            && let Some(instance) = injector.get_instance::<T>(key)
+        {
+            return Ok(instance.value());
+        }
+
+        let chain = self.chain.enter(key, provider.scope);
+        let injector = Injector {
+            chain: chain.clone(),
+            ..injector.factory_injector(self, provider.scope)
+        };
+
+        if provider.scope == Scope::Scoped {
+            return injector
+                .resolve_scoped::<T>(key, &provider)
+                .map_err(|error| error.with_path(|| chain.path()));
+        }
+
+        let instance =
+            (provider.factory)(&injector).map_err(|error| error.with_path(|| chain.path()))?;
+        let instance = Shared::new(instance);
+
+        match provider.scope {
+            Scope::Root => {
+                let root = injector.root_injector();
+                root.store_instance::<T>(key, instance.clone());
+                root.track_dispose::<T>(key, &provider, &instance);
+            }
+
+            Scope::Module => {
+                injector.store_instance::<T>(key, instance.clone());
+                injector.track_dispose::<T>(key, &provider, &instance);
+            }
+
+            Scope::Transient | Scope::Scoped => {}
+        }
+
+        Ok(instance.value())
+    }
+
+    /// Returns the instance of a Scoped provider cached in the active scope,
+    /// building it with this injector on first use.
+    fn resolve_scoped<T>(&self, key: &Key, provider: &Provider<T>) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        let scope = self
+            .active_scope()
+            .ok_or_else(|| Error::no_active_scope(&key.describe()))?;
+
+        if let Some(instance) = scope.get_scoped_instance::<T>(key) {
+            return Ok(instance.value());
+        }
+
+        let instance = Shared::new((provider.factory)(self)?);
+        scope.insert_scoped(key, instance.clone());
+        scope.track_dispose::<T>(key, provider, &instance);
+
+        Ok(instance.value())
+    }
+
+    /// Adds a provider to the multi-binding of `T`.
+    ///
+    /// Unlike [`try_provide`](Injector::try_provide), any number of providers
+    /// can be registered for the same type; they are collected with
+    /// [`resolve_all`](Injector::resolve_all). Root contributions are stored
+    /// in the root injector, all others in this injector. Each
+    /// contribution is cached according to its own scope and built by the
+    /// injector it is stored in. Contributions of an application module are
+    /// also listed in the root injector once the module is loaded, so every
+    /// module collects them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Provider, Shared};
+    ///
+    /// trait Plugin: Send + Sync {
+    ///     fn name(&self) -> &str;
+    /// }
+    ///
+    /// struct Metrics;
+    /// impl Plugin for Metrics {
+    ///     fn name(&self) -> &str { "metrics" }
+    /// }
+    ///
+    /// struct Auth;
+    /// impl Plugin for Auth {
+    ///     fn name(&self) -> &str { "auth" }
+    /// }
+    ///
+    /// let injector = Injector::root();
+    /// injector
+    ///     .provide_multi::<dyn Plugin>(Provider::root(|_| Shared::new(Metrics) as Shared<dyn Plugin>))
+    ///     .provide_multi::<dyn Plugin>(Provider::transient(|_| Shared::new(Auth) as Shared<dyn Plugin>));
+    ///
+    /// let names: Vec<_> = injector
+    ///     .resolve_all::<dyn Plugin>()
+    ///     .iter()
+    ///     .map(|plugin| plugin.name().to_string())
+    ///     .collect();
+    /// assert_eq!(names, ["metrics", "auth"]);
+    /// ```
+    pub fn provide_multi<T>(&self, provider: Provider<T>) -> &Self
+    where
+        T: ?Sized + 'static,
+    {
+        self.try_provide_multi::<T>(provider).unwrap();
+        self
+    }
+
+    /// Adds a provider to the multi-binding of `T`, failing when the injector
+    /// it is stored in is frozen.
+    ///
+    /// See [`provide_multi`](Injector::provide_multi).
+    pub fn try_provide_multi<T>(&self, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + 'static,
+    {
+        let owner = match provider.scope {
+            Scope::Root => self.root_injector(),
+            Scope::Module | Scope::Transient | Scope::Scoped => self.clone(),
+        };
+
+        owner.push_contribution(
+            TypeId::of::<T>(),
+            std::any::type_name::<T>(),
+            Shared::new(provider),
+            None,
+        )
+    }
+
+    /// Resolves every contribution to the multi-binding of `T`.
+    ///
+    /// Contributions from ancestors come first, then those of this injector,
+    /// each group in registration order. An empty vector is returned when
+    /// nothing was contributed.
+    pub fn try_resolve_all<T>(&self) -> Result<Vec<Shared<T>>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        let key = Key::of::<Vec<Shared<T>>>();
+        let chain = self.chain.push(&key)?;
+        let resolver = Injector {
+            chain: chain.clone(),
+            ..self.clone()
+        };
+
+        let mut resolved = Vec::new();
+
+        for (owner, contribution) in self.contributions(TypeId::of::<T>()) {
+            let instance = resolver
+                .resolve_contribution::<T>(&key, &owner, &contribution)
+                .map_err(|error| error.with_path(|| chain.path()))?;
+            resolved.push(instance);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Resolves every contribution to the multi-binding of `T`, panicking on
+    /// failure.
+    ///
+    /// See [`try_resolve_all`](Injector::try_resolve_all).
+    pub fn resolve_all<T>(&self) -> Vec<Shared<T>>
+    where
+        T: ?Sized + 'static,
+    {
+        self.try_resolve_all::<T>().unwrap()
+    }
+
+    /// Adds a keyed entry to the map multi-binding of `T`.
+    ///
+    /// Entries are collected with [`resolve_map`](Injector::resolve_map) and
+    /// follow the same scope and storage rules as
+    /// [`provide_multi`](Injector::provide_multi). Registering a key that is
+    /// already visible from the target injector fails with
+    /// [`ErrorKind::DuplicateMapKey`](crate::error::ErrorKind::DuplicateMapKey).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Provider, Shared};
+    ///
+    /// trait Handler: Send + Sync {
+    ///     fn handle(&self) -> String;
+    /// }
+    ///
+    /// struct Create;
+    /// impl Handler for Create {
+    ///     fn handle(&self) -> String { "created".to_string() }
+    /// }
+    ///
+    /// let injector = Injector::root();
+    /// injector
+    ///     .try_provide_map_entry::<&str, dyn Handler>(
+    ///         "create",
+    ///         Provider::root(|_| Shared::new(Create) as Shared<dyn Handler>),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let handlers = injector.resolve_map::<&str, dyn Handler>();
+    /// assert_eq!(handlers["create"].handle(), "created");
+    /// ```
+    pub fn try_provide_map_entry<K, T>(&self, key: K, provider: Provider<T>) -> Result<(), Error>
+    where
+        K: Eq + Hash + Clone + std::fmt::Debug + 'static,
+        T: ?Sized + 'static,
+    {
+        let owner = match provider.scope {
+            Scope::Root => self.root_injector(),
+            Scope::Module | Scope::Transient | Scope::Scoped => self.clone(),
+        };
+
+        let map_id = TypeId::of::<HashMap<K, Shared<T>>>();
+
+        let duplicate = owner
+            .contributions(map_id)
+            .iter()
+            .any(|(_, contribution)| contribution.map_key::<K>() == Some(&key));
+
+        if duplicate {
+            return Err(Error::duplicate_map_key(
+                std::any::type_name::<HashMap<K, Shared<T>>>(),
+                &format!("{:?}", key),
+            ));
+        }
+
+        owner.push_contribution(
+            map_id,
+            std::any::type_name::<HashMap<K, Shared<T>>>(),
+            Shared::new(provider),
+            Some(Shared::new(key)),
+        )
+    }
+
+    /// Adds a keyed entry to the map multi-binding of `T`, panicking on
+    /// failure.
+    ///
+    /// See [`try_provide_map_entry`](Injector::try_provide_map_entry).
+    pub fn provide_map_entry<K, T>(&self, key: K, provider: Provider<T>) -> &Self
+    where
+        K: Eq + Hash + Clone + std::fmt::Debug + 'static,
+        T: ?Sized + 'static,
+    {
+        self.try_provide_map_entry::<K, T>(key, provider).unwrap();
+        self
+    }
+
+    /// Resolves every entry of the map multi-binding of `T`.
+    ///
+    /// Entries are collected from this injector and all its ancestors, which
+    /// in an application includes those of every loaded module. Two visible
+    /// entries with the same key, for instance contributed by a standalone
+    /// injector and its parent, fail with
+    /// [`ErrorKind::DuplicateMapKey`](crate::error::ErrorKind::DuplicateMapKey).
+    pub fn try_resolve_map<K, T>(&self) -> Result<HashMap<K, Shared<T>>, Error>
+    where
+        K: Eq + Hash + Clone + std::fmt::Debug + 'static,
+        T: ?Sized + 'static,
+    {
+        let key = Key::of::<HashMap<K, Shared<T>>>();
+        let chain = self.chain.push(&key)?;
+        let resolver = Injector {
+            chain: chain.clone(),
+            ..self.clone()
+        };
+
+        let mut resolved = HashMap::new();
+
+        for (owner, contribution) in self.contributions(key.type_id) {
+            let Some(map_key) = contribution.map_key::<K>() else {
+                return Err(Error::type_mismatch(&key.describe()));
+            };
+
+            if resolved.contains_key(map_key) {
+                return Err(Error::duplicate_map_key(
+                    key.type_name,
+                    &format!("{:?}", map_key),
+                ));
+            }
+
+            let instance = resolver
+                .resolve_contribution::<T>(&key, &owner, &contribution)
+                .map_err(|error| error.with_path(|| chain.path()))?;
+            resolved.insert(map_key.clone(), instance);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Resolves every entry of the map multi-binding of `T`, panicking on
+    /// failure.
+    ///
+    /// See [`try_resolve_map`](Injector::try_resolve_map).
+    pub fn resolve_map<K, T>(&self) -> HashMap<K, Shared<T>>
+    where
+        K: Eq + Hash + Clone + std::fmt::Debug + 'static,
+        T: ?Sized + 'static,
+    {
+        self.try_resolve_map::<K, T>().unwrap()
+    }
+
+    fn resolve_contribution<T>(
+        &self,
+        key: &Key,
+        owner: &Injector,
+        contribution: &Contribution,
+    ) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        let cached = contribution.instance.borrow().clone();
+
+        let provider = contribution
+            .provider
+            .clone()
+            .downcast::<Provider<T>>()
+            .map_err(|_| Error::type_mismatch(&key.describe()))?;
+
+        self.check_captive(
+            self.chain.captor(provider.scope),
+            &Key::of::<T>(),
+            provider.scope,
+        )?;
+
+        if let Some(instance) = cached.and_then(|entry| entry.downcast::<Instance<T>>().ok()) {
+            return Ok(instance.value());
+        }
+
+        let injector = owner.factory_injector(self, provider.scope);
+
+        if provider.scope == Scope::Scoped {
+            let key = contribution.instance_key(key);
+            return injector.resolve_scoped::<T>(&key, &provider);
+        }
+
+        let instance = Shared::new((provider.factory)(&injector)?);
+
+        if provider.scope != Scope::Transient {
+            contribution.instance.replace(Some(instance.clone()));
+            owner.track_dispose::<T>(&Key::of::<T>(), &provider, &instance);
+        }
+
+        Ok(instance.value())
+    }
+
+    /// Resolves `T`, awaiting its async factory when the provider has one.
+    ///
+    /// Synchronous providers are resolved exactly as by
+    /// [`try_resolve`](Injector::try_resolve). For Module and Root singletons,
+    /// concurrent first-time resolutions share a single construction: one of
+    /// them runs the factory while the others wait for its outcome. If that
+    /// resolution is dropped before finishing, a waiting one takes over.
+    ///
+    /// The future only relies on `std::task` wakers and can be polled by any
+    /// executor.
+    pub async fn try_resolve_async<T>(&self) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        self.resolve_key_async::<T>(&Key::of::<T>()).await
+    }
+
+    /// Resolves `T` asynchronously, panicking on failure.
+    ///
+    /// See [`try_resolve_async`](Injector::try_resolve_async).
+    pub async fn resolve_async<T>(&self) -> Shared<T>
+    where
+        T: ?Sized + 'static,
+    {
+        self.try_resolve_async::<T>().await.unwrap()
+    }
+
+    /// Resolves the provider registered for `T` under `name`, awaiting its
+    /// async factory when it has one.
+    pub async fn try_resolve_named_async<T>(&self, name: &str) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        self.resolve_key_async::<T>(&Key::named::<T>(name)).await
+    }
+
+    async fn resolve_key_async<T>(&self, key: &Key) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + 'static,
+    {
+        self.chain.check(key)?;
+        let (injector, _) = self
+            .locate(key)
+            .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+        loop {
+            let provider = injector
+                .resolve_provider::<T>(key)
+                .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+            injector
+                .check_captive(self.chain.captor(provider.scope), key, provider.scope)
+                .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+            if provider.scope.is_singleton()
+                && let Some(instance) = injector.get_instance::<T>(key)
+            {
+                return Ok(instance.value());
+            }
+
+            let Some(async_factory) = &provider.async_factory else {
+                return injector
+                    .in_scope_of(self, provider.scope)
+                    .resolve_key::<T>(key);
+            };
+
+            let chain = self.chain.enter(key, provider.scope);
+            let resolver = Injector {
+                chain: chain.clone(),
+                ..injector.factory_injector(self, provider.scope)
+            };
+
+            let owner = match provider.scope {
+                Scope::Transient => {
+                    let instance = async_factory(resolver)
+                        .await
+                        .map_err(|error| error.with_path(|| chain.path()))?;
+                    return Ok(instance.value());
+                }
+                Scope::Root => injector.root_injector(),
+                Scope::Module => injector.clone(),
+                Scope::Scoped => {
+                    let scope = self.active_scope().ok_or_else(|| {
+                        Error::no_active_scope(&key.describe()).with_path(|| chain.path())
+                    })?;
+
+                    if let Some(instance) = scope.get_scoped_instance::<T>(key) {
+                        return Ok(instance.value());
+                    }
+                    scope
+                }
+            };
+
+            match owner.begin_init(key) {
+                Init::Owned(mut ticket) => {
+                    let result = async_factory(resolver)
+                        .await
+                        .map_err(|error| error.with_path(|| chain.path()));
+
+                    return match result {
+                        Ok(instance) => {
+                            let instance = Shared::new(instance);
+                            if provider.scope == Scope::Scoped {
+                                owner.insert_scoped(key, instance.clone());
+                            } else {
+                                owner.store_instance::<T>(key, instance.clone());
+                            }
+                            owner.track_dispose::<T>(key, &provider, &instance);
+                            ticket.outcome = Some(Ok(()));
+                            Ok(instance.value())
+                        }
+                        Err(error) => {
+                            ticket.outcome = Some(Err(error.clone()));
+                            Err(error)
+                        }
+                    };
+                }
+                Init::Wait(pending) => pending.wait().await?,
+                Init::Cached => {}
+            }
+        }
+    }
+}
+
+#[cfg(feature = "thread-safe")]
+impl Injector {
+    pub fn try_provide<T>(&self, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.provide_key::<T>(Key::of::<T>(), provider)
+    }
+
+    pub fn provide<T>(&self, provider: Provider<T>) -> &Self
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_provide::<T>(provider).unwrap();
+        self
+    }
+
+    /// Registers a provider for `T` under `name`.
+    ///
+    /// Named registrations live next to the unnamed one, so several bindings
+    /// of the same type can coexist. Scope rules are the same as for
+    /// [`try_provide`](Injector::try_provide): Root providers are stored in the
+    /// root injector, all others in this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Provider, Shared};
+    ///
+    /// let injector = Injector::root();
+    /// injector
+    ///     .try_provide_named::<String>("primary", Provider::root(|_| Shared::new("db-1".to_string())))
+    ///     .unwrap();
+    /// injector
+    ///     .try_provide_named::<String>("replica", Provider::root(|_| Shared::new("db-2".to_string())))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(*injector.resolve_named::<String>("replica"), "db-2");
+    /// ```
+    pub fn try_provide_named<T>(&self, name: &str, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.provide_key::<T>(Key::named::<T>(name), provider)
+    }
+
+    /// Registers a named provider, panicking on failure.
+    ///
+    /// See [`try_provide_named`](Injector::try_provide_named).
+    pub fn provide_named<T>(&self, name: &str, provider: Provider<T>) -> &Self
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_provide_named::<T>(name, provider).unwrap();
+        self
+    }
+
+    fn provide_key<T>(&self, key: Key, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let eager = (provider.eager && provider.scope.is_singleton()).then(|| key.clone());
+
+        match provider.scope {
+            Scope::Root => {
+                let root = self.root_injector();
+                root.store_provider::<T>(key, provider)?;
+            }
+
+            Scope::Module | Scope::Transient | Scope::Scoped => {
+                self.store_provider::<T>(key, provider)?
+            }
+        }
+
+        if let Some(key) = eager {
+            self.push_eager(key, Self::resolve_eager::<T>);
+        }
+        Ok(())
+    }
+
+    fn resolve_eager<T>(&self, key: &Key) -> Result<(), Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.resolve_key::<T>(key).map(|_| ())
+    }
+
+    pub(crate) fn resolve_provider<T>(&self, key: &Key) -> Result<Shared<Provider<T>>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let any_provider = self
+            .get_provider(key)
+            .ok_or_else(|| Error::service_not_provided(&key.describe()))?;
+
+        downcast_provider::<T>(any_provider, key)
+    }
+
+    pub(crate) fn store_instance<T>(&self, key: &Key, instance: Shared<Instance<T>>)
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.insert_instance(key, instance);
+    }
+
+    /// Records the dispose hook of `provider` for an instance cached here.
+    fn track_dispose<T>(&self, key: &Key, provider: &Provider<T>, instance: &Shared<Instance<T>>)
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let Some(hook) = provider.dispose else {
+            return;
+        };
+
+        let value = instance.value();
+        let hook = match hook {
+            DisposeHook::Sync(dispose) => ErasedDispose::Sync(Box::new(move || dispose(&value))),
+            DisposeHook::Async(dispose) => ErasedDispose::Async(Box::new(move || dispose(value))),
+        };
+
+        self.push_disposer(Disposer {
+            type_name: key.describe(),
+            hook,
+        });
+    }
+
+    pub(crate) fn store_provider<T>(&self, key: Key, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let scope = provider.scope.to_string();
+        self.insert_provider(key, Shared::new(provider), &scope)
+    }
+
+    pub(crate) fn get_instance<T>(&self, key: &Key) -> Option<Shared<Instance<T>>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.get_cached(key)
+            .and_then(|instance| instance.downcast::<Instance<T>>().ok())
+    }
+
+    fn get_scoped_instance<T>(&self, key: &Key) -> Option<Shared<Instance<T>>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.get_scoped(key)
+            .and_then(|instance| instance.downcast::<Instance<T>>().ok())
+    }
+
+    pub fn try_resolve<T>(&self) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.resolve_key::<T>(&Key::of::<T>())
+    }
+
+    pub fn resolve<T>(&self) -> Shared<T>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_resolve::<T>().unwrap()
+    }
+
+    pub fn optional_resolve<T>(&self) -> Option<Shared<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_resolve::<T>().ok()
+    }
+
+    /// Resolves the provider registered for `T` under `name`.
+    ///
+    /// The lookup walks the parent chain exactly like
+    /// [`try_resolve`](Injector::try_resolve), and the instance is cached
+    /// according to the provider's scope.
+    pub fn try_resolve_named<T>(&self, name: &str) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.resolve_key::<T>(&Key::named::<T>(name))
+    }
+
+    /// Resolves a named provider, panicking on failure.
+    ///
+    /// See [`try_resolve_named`](Injector::try_resolve_named).
+    pub fn resolve_named<T>(&self, name: &str) -> Shared<T>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_resolve_named::<T>(name).unwrap()
+    }
+
+    /// Resolves a named provider, returning `None` on failure.
+    pub fn optional_resolve_named<T>(&self, name: &str) -> Option<Shared<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_resolve_named::<T>(name).ok()
+    }
+
+    fn resolve_key<T>(&self, key: &Key) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.chain.check(key)?;
+
+        let located = self
+            .locate(key)
+            .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+        self.resolve_located::<T>(key, located)
+    }
+
+    /// Resolves `key` with the provider `entry` registered in `injector`.
+    fn resolve_located<T>(
+        &self,
+        key: &Key,
+        (injector, entry): (Injector, Entry),
+    ) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let provider = downcast_provider::<T>(entry, key)
+            .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+        injector
+            .check_captive(self.chain.captor(provider.scope), key, provider.scope)
+            .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+        if provider.scope.is_singleton()
+            && let Some(instance) = injector.get_instance::<T>(key)
+        {
+            return Ok(instance.value());
+        }
+
+        let chain = self.chain.enter(key, provider.scope);
+        let injector = Injector {
+            chain: chain.clone(),
+            ..injector.factory_injector(self, provider.scope)
+        };
+
+        if provider.scope == Scope::Scoped {
+            return injector
+                .resolve_scoped::<T>(key, &provider)
+                .map_err(|error| error.with_path(|| chain.path()));
+        }
+
+        if provider.scope == Scope::Transient {
+            let instance =
+                (provider.factory)(&injector).map_err(|error| error.with_path(|| chain.path()))?;
+            return Ok(instance.value());
+        }
+
+        // Root singletons are registered in the root injector, which the
+        // factory injector already is.
+        injector
+            .build_once::<T>(
+                &injector,
+                key,
+                &provider,
+                || injector.get_instance::<T>(key),
+                |instance| {
+                    injector.store_instance::<T>(key, instance.clone());
+                    injector.track_dispose::<T>(key, &provider, &instance);
+                },
+            )
+            .map_err(|error| error.with_path(|| chain.path()))
+    }
+
+    /// Returns the instance `cached` finds, building it with this injector
+    /// and handing it to `store` unless another thread already is, in which
+    /// case this one blocks until that construction ends.
+    ///
+    /// The construction is claimed under `key` in `owner`. If it fails, its
+    /// error is returned to the waiting threads too; if it panicked, one of
+    /// them builds the instance instead. A wait that would deadlock fails
+    /// with a circular dependency.
+    fn build_once<T>(
+        &self,
+        owner: &Injector,
+        key: &Key,
+        provider: &Provider<T>,
+        cached: impl Fn() -> Option<Shared<Instance<T>>>,
+        store: impl FnOnce(Shared<Instance<T>>),
+    ) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        loop {
+            if let Some(instance) = cached() {
+                return Ok(instance.value());
+            }
+
+            match owner.begin_init(key) {
+                Init::Owned(mut ticket) => {
+                    // Stored by a construction that ended since the lookup.
+                    if let Some(instance) = cached() {
+                        return Ok(instance.value());
+                    }
+
+                    let resolver = Injector {
+                        chain: self.chain.building(&ticket.pending),
+                        ..self.clone()
+                    };
+
+                    return match (provider.factory)(&resolver) {
+                        Ok(instance) => {
+                            let instance = Shared::new(instance);
+                            store(instance.clone());
+                            ticket.outcome = Some(Ok(()));
+                            Ok(instance.value())
+                        }
+                        Err(error) => {
+                            ticket.outcome = Some(Err(error.clone()));
+                            Err(error)
+                        }
+                    };
+                }
+                Init::Wait(pending) => pending.wait_blocking(
+                    self.chain.path(),
+                    self.chain.held(),
+                    self.init_timeout(),
+                )?,
+                Init::Cached => {}
+            }
+        }
+    }
+
+    /// Returns the instance of a Scoped provider cached in the active scope,
+    /// building it with this injector on first use.
+    fn resolve_scoped<T>(&self, key: &Key, provider: &Provider<T>) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let scope = self
+            .active_scope()
+            .ok_or_else(|| Error::no_active_scope(&key.describe()))?;
+
+        self.build_once::<T>(
+            &scope,
+            key,
+            provider,
+            || scope.get_scoped_instance::<T>(key),
+            |instance| {
+                scope.insert_scoped(key, instance.clone());
+                scope.track_dispose::<T>(key, provider, &instance);
+            },
+        )
+    }
+
+    /// Adds a provider to the multi-binding of `T`.
+    ///
+    /// Unlike [`try_provide`](Injector::try_provide), any number of providers
+    /// can be registered for the same type; they are collected with
+    /// [`resolve_all`](Injector::resolve_all). Root contributions are stored
+    /// in the root injector, all others in this injector. Each
+    /// contribution is cached according to its own scope and built by the
+    /// injector it is stored in. Contributions of an application module are
+    /// also listed in the root injector once the module is loaded, so every
+    /// module collects them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Provider, Shared};
+    ///
+    /// trait Plugin: Send + Sync {
+    ///     fn name(&self) -> &str;
+    /// }
+    ///
+    /// struct Metrics;
+    /// impl Plugin for Metrics {
+    ///     fn name(&self) -> &str { "metrics" }
+    /// }
+    ///
+    /// struct Auth;
+    /// impl Plugin for Auth {
+    ///     fn name(&self) -> &str { "auth" }
+    /// }
+    ///
+    /// let injector = Injector::root();
+    /// injector
+    ///     .provide_multi::<dyn Plugin>(Provider::root(|_| Shared::new(Metrics) as Shared<dyn Plugin>))
+    ///     .provide_multi::<dyn Plugin>(Provider::transient(|_| Shared::new(Auth) as Shared<dyn Plugin>));
+    ///
+    /// let names: Vec<_> = injector
+    ///     .resolve_all::<dyn Plugin>()
+    ///     .iter()
+    ///     .map(|plugin| plugin.name().to_string())
+    ///     .collect();
+    /// assert_eq!(names, ["metrics", "auth"]);
+    /// ```
+    pub fn provide_multi<T>(&self, provider: Provider<T>) -> &Self
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_provide_multi::<T>(provider).unwrap();
+        self
+    }
+
+    /// Adds a provider to the multi-binding of `T`, failing when the injector
+    /// it is stored in is frozen.
+    ///
+    /// See [`provide_multi`](Injector::provide_multi).
+    pub fn try_provide_multi<T>(&self, provider: Provider<T>) -> Result<(), Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let owner = match provider.scope {
+            Scope::Root => self.root_injector(),
+            Scope::Module | Scope::Transient | Scope::Scoped => self.clone(),
+        };
+
+        owner.push_contribution(
+            TypeId::of::<T>(),
+            std::any::type_name::<T>(),
+            Shared::new(provider),
+            None,
+        )
+    }
+
+    /// Resolves every contribution to the multi-binding of `T`.
+    ///
+    /// Contributions from ancestors come first, then those of this injector,
+    /// each group in registration order. An empty vector is returned when
+    /// nothing was contributed.
+    pub fn try_resolve_all<T>(&self) -> Result<Vec<Shared<T>>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let key = Key::of::<Vec<Shared<T>>>();
+        let chain = self.chain.push(&key)?;
+        let resolver = Injector {
+            chain: chain.clone(),
+            ..self.clone()
+        };
+
+        let mut resolved = Vec::new();
+
+        for (owner, contribution) in self.contributions(TypeId::of::<T>()) {
+            let instance = resolver
+                .resolve_contribution::<T>(&key, &owner, &contribution)
+                .map_err(|error| error.with_path(|| chain.path()))?;
+            resolved.push(instance);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Resolves every contribution to the multi-binding of `T`, panicking on
+    /// failure.
+    ///
+    /// See [`try_resolve_all`](Injector::try_resolve_all).
+    pub fn resolve_all<T>(&self) -> Vec<Shared<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_resolve_all::<T>().unwrap()
+    }
+
+    /// Adds a keyed entry to the map multi-binding of `T`.
+    ///
+    /// Entries are collected with [`resolve_map`](Injector::resolve_map) and
+    /// follow the same scope and storage rules as
+    /// [`provide_multi`](Injector::provide_multi). Registering a key that is
+    /// already visible from the target injector fails with
+    /// [`ErrorKind::DuplicateMapKey`](crate::error::ErrorKind::DuplicateMapKey).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Provider, Shared};
+    ///
+    /// trait Handler: Send + Sync {
+    ///     fn handle(&self) -> String;
+    /// }
+    ///
+    /// struct Create;
+    /// impl Handler for Create {
+    ///     fn handle(&self) -> String { "created".to_string() }
+    /// }
+    ///
+    /// let injector = Injector::root();
+    /// injector
+    ///     .try_provide_map_entry::<&str, dyn Handler>(
+    ///         "create",
+    ///         Provider::root(|_| Shared::new(Create) as Shared<dyn Handler>),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let handlers = injector.resolve_map::<&str, dyn Handler>();
+    /// assert_eq!(handlers["create"].handle(), "created");
+    /// ```
+    pub fn try_provide_map_entry<K, T>(&self, key: K, provider: Provider<T>) -> Result<(), Error>
+    where
+        K: Eq + Hash + Clone + std::fmt::Debug + Send + Sync + 'static,
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let owner = match provider.scope {
+            Scope::Root => self.root_injector(),
+            Scope::Module | Scope::Transient | Scope::Scoped => self.clone(),
+        };
+
+        let map_id = TypeId::of::<HashMap<K, Shared<T>>>();
+
+        let duplicate = owner
+            .contributions(map_id)
+            .iter()
+            .any(|(_, contribution)| contribution.map_key::<K>() == Some(&key));
+
+        if duplicate {
+            return Err(Error::duplicate_map_key(
+                std::any::type_name::<HashMap<K, Shared<T>>>(),
+                &format!("{:?}", key),
+            ));
+        }
+
+        owner.push_contribution(
+            map_id,
+            std::any::type_name::<HashMap<K, Shared<T>>>(),
+            Shared::new(provider),
+            Some(Shared::new(key)),
+        )
+    }
+
+    /// Adds a keyed entry to the map multi-binding of `T`, panicking on
+    /// failure.
+    ///
+    /// See [`try_provide_map_entry`](Injector::try_provide_map_entry).
+    pub fn provide_map_entry<K, T>(&self, key: K, provider: Provider<T>) -> &Self
+    where
+        K: Eq + Hash + Clone + std::fmt::Debug + Send + Sync + 'static,
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_provide_map_entry::<K, T>(key, provider).unwrap();
+        self
+    }
+
+    /// Resolves every entry of the map multi-binding of `T`.
+    ///
+    /// Entries are collected from this injector and all its ancestors, which
+    /// in an application includes those of every loaded module. Two visible
+    /// entries with the same key, for instance contributed by a standalone
+    /// injector and its parent, fail with
+    /// [`ErrorKind::DuplicateMapKey`](crate::error::ErrorKind::DuplicateMapKey).
+    pub fn try_resolve_map<K, T>(&self) -> Result<HashMap<K, Shared<T>>, Error>
+    where
+        K: Eq + Hash + Clone + std::fmt::Debug + Send + Sync + 'static,
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let key = Key::of::<HashMap<K, Shared<T>>>();
+        let chain = self.chain.push(&key)?;
+        let resolver = Injector {
+            chain: chain.clone(),
+            ..self.clone()
+        };
+
+        let mut resolved = HashMap::new();
+
+        for (owner, contribution) in self.contributions(key.type_id) {
+            let Some(map_key) = contribution.map_key::<K>() else {
+                return Err(Error::type_mismatch(&key.describe()));
+            };
+
+            if resolved.contains_key(map_key) {
+                return Err(Error::duplicate_map_key(
+                    key.type_name,
+                    &format!("{:?}", map_key),
+                ));
+            }
+
+            let instance = resolver
+                .resolve_contribution::<T>(&key, &owner, &contribution)
+                .map_err(|error| error.with_path(|| chain.path()))?;
+            resolved.insert(map_key.clone(), instance);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Resolves every entry of the map multi-binding of `T`, panicking on
+    /// failure.
+    ///
+    /// See [`try_resolve_map`](Injector::try_resolve_map).
+    pub fn resolve_map<K, T>(&self) -> HashMap<K, Shared<T>>
+    where
+        K: Eq + Hash + Clone + std::fmt::Debug + Send + Sync + 'static,
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_resolve_map::<K, T>().unwrap()
+    }
+
+    fn resolve_contribution<T>(
+        &self,
+        key: &Key,
+        owner: &Injector,
+        contribution: &Contribution,
+    ) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        let provider = contribution
+            .provider
+            .clone()
+            .downcast::<Provider<T>>()
+            .map_err(|_| Error::type_mismatch(&key.describe()))?;
+
+        self.check_captive(
+            self.chain.captor(provider.scope),
+            &Key::of::<T>(),
+            provider.scope,
+        )?;
+
+        let injector = owner.factory_injector(self, provider.scope);
+
+        if provider.scope == Scope::Scoped {
+            let key = contribution.instance_key(key);
+            return injector.resolve_scoped::<T>(&key, &provider);
+        }
+
+        if provider.scope == Scope::Transient {
+            return Ok((provider.factory)(&injector)?.value());
+        }
+
+        injector.build_once::<T>(
+            owner,
+            &contribution.instance_key(key),
+            &provider,
+            || {
+                let cached = contribution.instance.read().unwrap().clone();
+                cached.and_then(|entry| entry.downcast::<Instance<T>>().ok())
+            },
+            |instance| {
+                contribution
+                    .instance
+                    .write()
+                    .unwrap()
+                    .replace(instance.clone());
+                owner.track_dispose::<T>(&Key::of::<T>(), &provider, &instance);
+            },
+        )
+    }
+
+    /// Resolves `T`, awaiting its async factory when the provider has one.
+    ///
+    /// Synchronous providers are resolved exactly as by
+    /// [`try_resolve`](Injector::try_resolve). For Module and Root singletons,
+    /// concurrent first-time resolutions share a single construction: one of
+    /// them runs the factory while the others wait for its outcome. If that
+    /// resolution is dropped before finishing, a waiting one takes over.
+    ///
+    /// The future only relies on `std::task` wakers and can be polled by any
+    /// executor.
+    pub async fn try_resolve_async<T>(&self) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.resolve_key_async::<T>(&Key::of::<T>()).await
+    }
+
+    /// Resolves `T` asynchronously, panicking on failure.
+    ///
+    /// See [`try_resolve_async`](Injector::try_resolve_async).
+    pub async fn resolve_async<T>(&self) -> Shared<T>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.try_resolve_async::<T>().await.unwrap()
+    }
+
+    /// Resolves the provider registered for `T` under `name`, awaiting its
+    /// async factory when it has one.
+    pub async fn try_resolve_named_async<T>(&self, name: &str) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.resolve_key_async::<T>(&Key::named::<T>(name)).await
+    }
+
+    async fn resolve_key_async<T>(&self, key: &Key) -> Result<Shared<T>, Error>
+    where
+        T: ?Sized + Send + Sync + 'static,
+    {
+        self.chain.check(key)?;
+        let (injector, _) = self
+            .locate(key)
+            .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+        loop {
+            let provider = injector
+                .resolve_provider::<T>(key)
+                .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+            injector
+                .check_captive(self.chain.captor(provider.scope), key, provider.scope)
+                .map_err(|error| error.with_path(|| self.chain.path_to(key)))?;
+
+            if provider.scope.is_singleton()
+                && let Some(instance) = injector.get_instance::<T>(key)
+            {
+                return Ok(instance.value());
+            }
+
+            let Some(async_factory) = &provider.async_factory else {
+                return injector
+                    .in_scope_of(self, provider.scope)
+                    .resolve_key::<T>(key);
+            };
+
+            let chain = self.chain.enter(key, provider.scope);
+            let resolver = Injector {
+                chain: chain.clone(),
+                ..injector.factory_injector(self, provider.scope)
+            };
+
+            let owner = match provider.scope {
+                Scope::Transient => {
+                    let instance = async_factory(resolver)
+                        .await
+                        .map_err(|error| error.with_path(|| chain.path()))?;
+                    return Ok(instance.value());
+                }
+                Scope::Root => injector.root_injector(),
+                Scope::Module => injector.clone(),
+                Scope::Scoped => {
+                    let scope = self.active_scope().ok_or_else(|| {
+                        Error::no_active_scope(&key.describe()).with_path(|| chain.path())
+                    })?;
+
+                    if let Some(instance) = scope.get_scoped_instance::<T>(key) {
+                        return Ok(instance.value());
+                    }
+                    scope
+                }
+            };
+
+            match owner.begin_init(key) {
+                Init::Owned(mut ticket) => {
+                    let resolver = Injector {
+                        chain: resolver.chain.building(&ticket.pending),
+                        ..resolver
+                    };
+                    let result = async_factory(resolver)
+                        .await
+                        .map_err(|error| error.with_path(|| chain.path()));
+
+                    return match result {
+                        Ok(instance) => {
+                            let instance = Shared::new(instance);
+                            if provider.scope == Scope::Scoped {
+                                owner.insert_scoped(key, instance.clone());
+                            } else {
+                                owner.store_instance::<T>(key, instance.clone());
+                            }
+                            owner.track_dispose::<T>(key, &provider, &instance);
+                            ticket.outcome = Some(Ok(()));
+                            Ok(instance.value())
+                        }
+                        Err(error) => {
+                            ticket.outcome = Some(Err(error.clone()));
+                            Err(error)
+                        }
+                    };
+                }
+                Init::Wait(pending) => pending.wait().await?,
+                Init::Cached => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    use std::future::Future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[cfg(not(feature = "thread-safe"))]
+    use std::cell::Cell;
+
+    struct Database {
+        url: String,
+    }
+
+    #[derive(Debug)]
+    struct ConnectionRefused;
+
+    impl std::fmt::Display for ConnectionRefused {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "connection refused")
+        }
+    }
+
+    impl std::error::Error for ConnectionRefused {}
+
+    #[test]
+    fn test_try_resolve_reports_factory_failure() {
+        let injector = Injector::root();
+        injector.provide::<Database>(Provider::try_singleton(|_| {
+            Err::<Shared<Database>, _>(ConnectionRefused)
+        }));
+
+        let err = injector.try_resolve::<Database>().err().unwrap();
+        assert!(err.kind == ErrorKind::FactoryFailed);
+        assert!(!err.message.contains("connection refused"));
+
+        let source = err.source.expect("factory error should be kept as source");
+        assert!(source.downcast_ref::<ConnectionRefused>().is_some());
+    }
+
+    #[test]
+    fn test_try_resolve_returns_value_from_fallible_factory() {
+        let injector = Injector::root();
+        injector.provide::<Database>(Provider::try_root(|_| {
+            Ok::<_, ConnectionRefused>(Shared::new(Database {
+                url: "sqlite::memory:".to_string(),
+            }))
+        }));
+
+        let database = injector.try_resolve::<Database>().unwrap();
+        assert_eq!(database.url, "sqlite::memory:");
+    }
+
+    #[test]
+    fn test_failed_singleton_is_not_cached() {
+        #[cfg(not(feature = "thread-safe"))]
+        let attempts = Shared::new(Cell::new(0));
+        #[cfg(feature = "thread-safe")]
+        let attempts = Shared::new(AtomicUsize::new(0));
+
+        let counter = attempts.clone();
+        let injector = Injector::root();
+        injector.provide::<Database>(Provider::try_singleton(move |_| {
+            #[cfg(not(feature = "thread-safe"))]
+            let attempt = counter.replace(counter.get() + 1);
+            #[cfg(feature = "thread-safe")]
+            let attempt = counter.fetch_add(1, Ordering::SeqCst);
+
+            if attempt == 0 {
+                return Err("database not ready".to_string());
+            }
+
+            Ok(Shared::new(Database {
+                url: format!("attempt-{}", attempt),
+            }))
+        }));
+
+        assert!(injector.try_resolve::<Database>().is_err());
+
+        let first = injector.try_resolve::<Database>().unwrap();
+        let second = injector.try_resolve::<Database>().unwrap();
+        assert_eq!(first.url, "attempt-1");
+        assert!(Shared::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn test_fallible_transient_propagates_error_every_time() {
+        let injector = Injector::root();
+        injector.provide::<u16>(Provider::try_transient(|_| {
+            "not-a-port".parse::<u16>().map(Shared::new)
+        }));
+
+        for _ in 0..2 {
+            let err = injector.try_resolve::<u16>().err().unwrap();
+            assert!(err.kind == ErrorKind::FactoryFailed);
+        }
+    }
+
+    struct Pool {
+        id: usize,
+    }
+
+    struct Repository {
+        pool: Shared<Pool>,
+    }
+
+    struct ServiceA;
+    struct ServiceB;
+
+    #[tokio::test]
+    async fn test_resolve_async_awaits_async_factory() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::root_async(|_| async {
+            tokio::task::yield_now().await;
+            Shared::new(Pool { id: 1 })
+        }));
+
+        let pool = injector.try_resolve_async::<Pool>().await.unwrap();
+        assert_eq!(pool.id, 1);
+
+        let again = injector.resolve_async::<Pool>().await;
+        assert!(Shared::ptr_eq(&pool, &again));
+    }
+
+    #[tokio::test]
+    async fn test_async_factory_resolves_async_dependencies() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::root_async(|_| async {
+            Shared::new(Pool { id: 7 })
+        }));
+        injector.provide::<Repository>(Provider::singleton_async(|injector| async move {
+            let pool = injector.resolve_async::<Pool>().await;
+            Shared::new(Repository { pool })
+        }));
+
+        let repository = injector.try_resolve_async::<Repository>().await.unwrap();
+        assert_eq!(repository.pool.id, 7);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_async_falls_back_to_sync_providers() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::singleton(|_| Shared::new(Pool { id: 3 })));
+
+        let pool = injector.try_resolve_async::<Pool>().await.unwrap();
+        assert_eq!(pool.id, 3);
+        assert!(Shared::ptr_eq(&pool, &injector.resolve::<Pool>()));
+    }
+
+    #[tokio::test]
+    async fn test_sync_resolve_of_async_provider() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::singleton_async(|_| async {
+            Shared::new(Pool { id: 4 })
+        }));
+
+        let err = injector.try_resolve::<Pool>().err().unwrap();
+        assert!(err.kind == ErrorKind::AsyncFactory);
+
+        // Once cached, the synchronous API can return the instance.
+        let pool = injector.resolve_async::<Pool>().await;
+        assert!(Shared::ptr_eq(&pool, &injector.resolve::<Pool>()));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_async_resolves_share_one_construction() {
+        let runs = Shared::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::root_async(move |_| {
+            let id = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                for _ in 0..3 {
+                    tokio::task::yield_now().await;
+                }
+                Shared::new(Pool { id })
+            }
+        }));
+
+        let (a, b, c) = tokio::join!(
+            injector.try_resolve_async::<Pool>(),
+            injector.try_resolve_async::<Pool>(),
+            injector.try_resolve_async::<Pool>(),
+        );
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        let (a, b, c) = (a.unwrap(), b.unwrap(), c.unwrap());
+        assert!(Shared::ptr_eq(&a, &b));
+        assert!(Shared::ptr_eq(&a, &c));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_waiters_share_async_failure() {
+        let runs = Shared::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::try_singleton_async(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async {
+                tokio::task::yield_now().await;
+                Err::<Shared<Pool>, _>("pool exhausted")
+            }
+        }));
+
+        let (a, b) = tokio::join!(
+            injector.try_resolve_async::<Pool>(),
+            injector.try_resolve_async::<Pool>(),
+        );
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(a.err().unwrap().kind == ErrorKind::FactoryFailed);
+        assert!(b.err().unwrap().kind == ErrorKind::FactoryFailed);
+
+        // The failure is not cached: the next resolution runs the factory again.
+        assert!(injector.try_resolve_async::<Pool>().await.is_err());
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_async_construction_can_be_retried() {
+        let runs = Shared::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::singleton_async(move |_| {
+            let id = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                tokio::task::yield_now().await;
+                Shared::new(Pool { id })
+            }
+        }));
+
+        {
+            let mut first = std::pin::pin!(injector.try_resolve_async::<Pool>());
+            let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+            assert!(first.as_mut().poll(&mut cx).is_pending());
+        }
+
+        let pool = injector.try_resolve_async::<Pool>().await.unwrap();
+        assert_eq!(pool.id, 1);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_async_circular_dependency_is_detected() {
+        let injector = Injector::root();
+        injector.provide::<ServiceA>(Provider::try_singleton_async(|injector| async move {
+            injector.try_resolve_async::<ServiceB>().await?;
+            Ok::<_, Error>(Shared::new(ServiceA))
+        }));
+        injector.provide::<ServiceB>(Provider::try_singleton_async(|injector| async move {
+            injector.try_resolve_async::<ServiceA>().await?;
+            Ok::<_, Error>(Shared::new(ServiceB))
+        }));
+
+        let err = injector
+            .try_resolve_async::<ServiceA>()
+            .await
+            .err()
+            .unwrap();
+        assert!(err.message.contains("Circular dependency"));
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_resolve_async_future_is_send() {
+        fn assert_send<F: Send>(_: F) {}
+
+        let injector = Injector::root();
+        assert_send(injector.try_resolve_async::<Pool>());
+        assert_send(injector.dispose_async());
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_async_singleton_is_built_once_across_threads() {
+        let runs = Shared::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+
+        let injector = Shared::new(Injector::root());
+        injector.provide::<Pool>(Provider::root_async(move |_| {
+            let id = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                for _ in 0..10 {
+                    tokio::task::yield_now().await;
+                }
+                Shared::new(Pool { id })
+            }
+        }));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let injector = injector.clone();
+                tokio::spawn(async move { injector.resolve_async::<Pool>().await })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap().id, 0);
+        }
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+
+    /// Resolves `T` from `threads` threads released at the same time.
+    #[cfg(feature = "thread-safe")]
+    fn resolve_contended<T, F>(threads: usize, resolve: F) -> Vec<Shared<T>>
+    where
+        T: ?Sized + Send + Sync + 'static,
+        F: Fn() -> Shared<T> + Send + Sync + 'static,
+    {
+        let resolve = Shared::new(resolve);
+        let barrier = Shared::new(std::sync::Barrier::new(threads));
+
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                let resolve = resolve.clone();
+                let barrier = barrier.clone();
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    resolve()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    }
+
+    /// Provider whose factory is slow enough for every thread to race on it.
+    #[cfg(feature = "thread-safe")]
+    fn slow_pool(scope: Scope, runs: &Shared<AtomicUsize>) -> Provider<Pool> {
+        let runs = runs.clone();
+        let factory = move |_: &Injector| {
+            let id = runs.fetch_add(1, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            Shared::new(Pool { id })
+        };
+
+        match scope {
+            Scope::Root => Provider::root(factory),
+            Scope::Module => Provider::singleton(factory),
+            Scope::Scoped => Provider::scoped(factory),
+            Scope::Transient => Provider::transient(factory),
+        }
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_singleton_is_built_once_across_threads() {
+        for scope in [Scope::Root, Scope::Module] {
+            let runs = Shared::new(AtomicUsize::new(0));
+            let injector = Shared::new(Injector::root());
+            injector.provide::<Pool>(slow_pool(scope, &runs));
+
+            let child = Injector::child(injector.clone());
+            let pools = resolve_contended(16, move || child.resolve::<Pool>());
+
+            assert_eq!(runs.load(Ordering::SeqCst), 1);
+            assert!(pools.iter().all(|pool| Shared::ptr_eq(pool, &pools[0])));
+        }
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_scoped_instance_is_built_once_across_threads() {
+        let runs = Shared::new(AtomicUsize::new(0));
+        let injector = Injector::root();
+        injector.provide::<Pool>(slow_pool(Scope::Scoped, &runs));
+
+        let scope = Shared::new(injector.create_scope());
+        let pools = resolve_contended(16, move || scope.resolve::<Pool>());
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(pools.iter().all(|pool| Shared::ptr_eq(pool, &pools[0])));
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_multi_binding_contribution_is_built_once_across_threads() {
+        let runs = Shared::new(AtomicUsize::new(0));
+        let injector = Shared::new(Injector::root());
+        injector.provide_multi::<Pool>(slow_pool(Scope::Root, &runs));
+
+        let resolver = injector.clone();
+        let pools = resolve_contended(16, move || resolver.resolve_all::<Pool>().remove(0));
+
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(pools.iter().all(|pool| Shared::ptr_eq(pool, &pools[0])));
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_failed_singleton_construction_is_reported_to_waiting_threads() {
+        let runs = Shared::new(AtomicUsize::new(0));
+        let counter = runs.clone();
+        let injector = Shared::new(Injector::root());
+        injector.provide::<Pool>(Provider::try_root(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            Err::<Shared<Pool>, _>(ConnectionRefused)
+        }));
+
+        let resolver = injector.clone();
+        let failures = resolve_contended(8, move || {
+            Shared::new(resolver.try_resolve::<Pool>().is_err())
+        });
+
+        assert!(failures.iter().all(|failed| **failed));
+        assert!(runs.load(Ordering::SeqCst) < failures.len());
+    }
+
+    /// Provides `T`, whose factory waits at `barrier` and then resolves `D`,
+    /// recording the error if that fails.
+    #[cfg(feature = "thread-safe")]
+    fn provide_meeting<T, D>(
+        injector: &Injector,
+        barrier: &Shared<std::sync::Barrier>,
+        errors: &Shared<std::sync::Mutex<Vec<Error>>>,
+        build: fn() -> T,
+    ) where
+        T: Send + Sync + 'static,
+        D: Send + Sync + 'static,
+    {
+        let (barrier, errors) = (barrier.clone(), errors.clone());
+        injector.provide::<T>(Provider::root(move |injector| {
+            barrier.wait();
+            if let Err(error) = injector.try_resolve::<D>() {
+                errors.lock().unwrap().push(error);
+            }
+            Shared::new(build())
+        }));
+    }
+
+    #[cfg(feature = "thread-safe")]
+    fn assert_circular_wait(errors: &[Error]) {
+        let a = std::any::type_name::<ServiceA>();
+        let b = std::any::type_name::<ServiceB>();
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].kind == ErrorKind::CircularDependency);
+        assert_eq!(errors[0].chain.len(), 3);
+        assert_eq!(errors[0].chain.first(), errors[0].chain.last());
+        assert!(
+            errors[0]
+                .message
+                .contains(&format!("resolving {} -> {}", a, b))
+        );
+        assert!(
+            errors[0]
+                .message
+                .contains(&format!("resolving {} -> {}", b, a))
+        );
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_threads_building_each_other_fail_instead_of_deadlocking() {
+        let barrier = Shared::new(std::sync::Barrier::new(2));
+        let errors = Shared::new(std::sync::Mutex::new(Vec::new()));
+
+        let injector = Shared::new(Injector::root());
+        provide_meeting::<ServiceA, ServiceB>(&injector, &barrier, &errors, || ServiceA);
+        provide_meeting::<ServiceB, ServiceA>(&injector, &barrier, &errors, || ServiceB);
+
+        let resolver = injector.clone();
+        let a = std::thread::spawn(move || resolver.resolve::<ServiceA>());
+        let resolver = injector.clone();
+        let b = std::thread::spawn(move || resolver.resolve::<ServiceB>());
+        a.join().unwrap();
+        b.join().unwrap();
+
+        assert_circular_wait(&errors.lock().unwrap());
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_circular_wait_through_a_handed_off_resolution_is_detected() {
+        let barrier = Shared::new(std::sync::Barrier::new(2));
+        let errors = Shared::new(std::sync::Mutex::new(Vec::new()));
+
+        let injector = Shared::new(Injector::root());
+        {
+            let (barrier, errors) = (barrier.clone(), errors.clone());
+            injector.provide::<ServiceA>(Provider::root(move |injector| {
+                barrier.wait();
+                let resolved = std::thread::scope(|scope| {
+                    scope
+                        .spawn(|| injector.try_resolve::<ServiceB>())
+                        .join()
+                        .unwrap()
+                });
+                if let Err(error) = resolved {
+                    errors.lock().unwrap().push(error);
+                }
+                Shared::new(ServiceA)
+            }));
+        }
+        provide_meeting::<ServiceB, ServiceA>(&injector, &barrier, &errors, || ServiceB);
+
+        let resolver = injector.clone();
+        let a = std::thread::spawn(move || resolver.resolve::<ServiceA>());
+        let resolver = injector.clone();
+        let b = std::thread::spawn(move || resolver.resolve::<ServiceB>());
+        a.join().unwrap();
+        b.join().unwrap();
+
+        assert_circular_wait(&errors.lock().unwrap());
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_waiting_for_a_slow_construction_times_out() {
+        let started = Shared::new(std::sync::Barrier::new(2));
+        let barrier = started.clone();
+
+        let injector = Shared::new(Injector::root());
+        injector.set_init_timeout(Some(std::time::Duration::from_millis(20)));
+        injector.provide::<Pool>(Provider::root(move |_| {
+            barrier.wait();
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            Shared::new(Pool { id: 1 })
+        }));
+
+        let resolver = injector.clone();
+        let builder = std::thread::spawn(move || resolver.resolve::<Pool>());
+        started.wait();
+
+        let err = injector.try_resolve::<Pool>().err().unwrap();
+        assert!(err.kind == ErrorKind::InitTimeout);
+        assert!(err.message.contains(std::any::type_name::<Pool>()));
+
+        assert_eq!(builder.join().unwrap().id, 1);
+        assert_eq!(injector.resolve::<Pool>().id, 1);
+    }
+
+    trait UserRepository: Send + Sync {
+        fn source(&self) -> &str;
+    }
+
+    struct SqlUserRepository {
+        source: &'static str,
+    }
+
+    impl UserRepository for SqlUserRepository {
+        fn source(&self) -> &str {
+            self.source
+        }
+    }
+
+    #[test]
+    fn test_named_providers_of_same_type_coexist() {
+        let injector = Injector::root();
+        injector.provide_named::<dyn UserRepository>(
+            "primary",
+            Provider::root(|_| {
+                Shared::new(SqlUserRepository { source: "primary" }) as Shared<dyn UserRepository>
+            }),
+        );
+        injector.provide_named::<dyn UserRepository>(
+            "replica",
+            Provider::root(|_| {
+                Shared::new(SqlUserRepository { source: "replica" }) as Shared<dyn UserRepository>
+            }),
+        );
+
+        let primary = injector.resolve_named::<dyn UserRepository>("primary");
+        let replica = injector.resolve_named::<dyn UserRepository>("replica");
+        assert_eq!(primary.source(), "primary");
+        assert_eq!(replica.source(), "replica");
+
+        let again = injector.resolve_named::<dyn UserRepository>("primary");
+        assert!(Shared::ptr_eq(&primary, &again));
+    }
+
+    #[test]
+    fn test_named_and_unnamed_bindings_are_independent() {
+        let injector = Injector::root();
+        injector.provide::<String>(Provider::root(|_| Shared::new("default".to_string())));
+        injector.provide_named::<String>(
+            "greeting",
+            Provider::transient(|_| Shared::new("hello".to_string())),
+        );
+
+        assert_eq!(*injector.resolve::<String>(), "default");
+        assert_eq!(*injector.resolve_named::<String>("greeting"), "hello");
+        assert!(
+            injector
+                .optional_resolve_named::<String>("missing")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_named_provider_is_found_through_parent_chain() {
+        let root = Shared::new(Injector::root());
+        root.provide_named::<String>(
+            "region",
+            Provider::singleton(|_| Shared::new("eu-west".to_string())),
+        );
+
+        let child = Injector::child(root.clone());
+        assert_eq!(*child.resolve_named::<String>("region"), "eu-west");
+    }
+
+    #[test]
+    fn test_duplicate_named_provider_is_rejected() {
+        let injector = Injector::root();
+        injector.provide_named::<String>("a", Provider::root(|_| Shared::new("1".to_string())));
+
+        let err = injector
+            .try_provide_named::<String>("a", Provider::root(|_| Shared::new("2".to_string())))
+            .err()
+            .unwrap();
+        assert!(err.kind == ErrorKind::ProviderAlreadyRegistered);
+        assert!(err.message.contains("named \"a\""));
+
+        assert!(
+            injector
+                .try_provide_named::<String>("b", Provider::root(|_| Shared::new("3".to_string())))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_missing_named_provider_reports_name() {
+        let injector = Injector::root();
+
+        let err = injector
+            .try_resolve_named::<String>("primary")
+            .err()
+            .unwrap();
+        assert!(err.kind == ErrorKind::ServiceNotProvided);
+        assert!(err.message.contains("named \"primary\""));
+    }
+
+    #[tokio::test]
+    async fn test_named_provider_resolves_async() {
+        let injector = Injector::root();
+        injector.provide_named::<Pool>(
+            "warm",
+            Provider::root_async(|_| async { Shared::new(Pool { id: 7 }) }),
+        );
+
+        let pool = injector
+            .try_resolve_named_async::<Pool>("warm")
+            .await
+            .unwrap();
+        assert_eq!(pool.id, 7);
+        assert!(injector.try_resolve_async::<Pool>().await.is_err());
+    }
+
+    trait Plugin: Send + Sync {
+        fn name(&self) -> String;
+    }
+
+    struct NamedPlugin(&'static str);
+
+    impl Plugin for NamedPlugin {
+        fn name(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    fn plugin(name: &'static str) -> Shared<dyn Plugin> {
+        Shared::new(NamedPlugin(name)) as Shared<dyn Plugin>
+    }
+
+    fn plugin_names(plugins: &[Shared<dyn Plugin>]) -> Vec<String> {
+        plugins.iter().map(|plugin| plugin.name()).collect()
+    }
+
+    #[test]
+    fn test_resolve_all_collects_contributions_in_order() {
+        let root = Shared::new(Injector::root());
+        root.provide_multi::<dyn Plugin>(Provider::singleton(|_| plugin("root-a")));
+
+        let child = Injector::child(root.clone());
+        child.provide_multi::<dyn Plugin>(Provider::singleton(|_| plugin("child-a")));
+        child.provide_multi::<dyn Plugin>(Provider::root(|_| plugin("root-b")));
+        child.provide_multi::<dyn Plugin>(Provider::transient(|_| plugin("child-b")));
+
+        assert_eq!(
+            plugin_names(&child.resolve_all::<dyn Plugin>()),
+            ["root-a", "root-b", "child-a", "child-b"]
+        );
+        assert_eq!(
+            plugin_names(&root.resolve_all::<dyn Plugin>()),
+            ["root-a", "root-b"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_all_applies_each_contribution_scope() {
+        let injector = Injector::root();
+        injector.provide_multi::<dyn Plugin>(Provider::singleton(|_| plugin("cached")));
+        injector.provide_multi::<dyn Plugin>(Provider::transient(|_| plugin("fresh")));
+
+        let first = injector.resolve_all::<dyn Plugin>();
+        let second = injector.resolve_all::<dyn Plugin>();
+
+        assert!(Shared::ptr_eq(&first[0], &second[0]));
+        assert!(!Shared::ptr_eq(&first[1], &second[1]));
+    }
+
+    #[test]
+    fn test_scoped_contributions_are_cached_apart_in_a_scope() {
+        let injector = Injector::root();
+        injector.provide_multi::<dyn Plugin>(Provider::scoped(|_| plugin("a")));
+        injector.provide_multi::<dyn Plugin>(Provider::scoped(|_| plugin("b")));
+        // A name cannot reach the instance key of a contribution.
+        injector.provide_named::<Vec<Shared<dyn Plugin>>>(
+            "contribution",
+            Provider::scoped(|_| Shared::new(Vec::new())),
+        );
+
+        let scope = injector.create_scope();
+        let first = scope.resolve_all::<dyn Plugin>();
+        let second = scope.resolve_all::<dyn Plugin>();
+        scope.resolve_named::<Vec<Shared<dyn Plugin>>>("contribution");
+
+        assert_eq!(plugin_names(&first), ["a", "b"]);
+        assert!(Shared::ptr_eq(&first[0], &second[0]));
+        assert!(Shared::ptr_eq(&first[1], &second[1]));
+
+        let key = Key::of::<Vec<Shared<dyn Plugin>>>();
+        for (_, contribution) in injector.contributions(TypeId::of::<dyn Plugin>()) {
+            assert!(contribution.instance_key(&key).name.is_none());
+        }
+    }
+
+    #[test]
+    fn test_multi_bindings_do_not_clash_with_single_binding() {
+        let injector = Injector::root();
+        injector.provide::<dyn Plugin>(Provider::root(|_| plugin("single")));
+        injector.provide_multi::<dyn Plugin>(Provider::root(|_| plugin("multi")));
+
+        assert_eq!(injector.resolve::<dyn Plugin>().name(), "single");
+        assert_eq!(
+            plugin_names(&injector.resolve_all::<dyn Plugin>()),
+            ["multi"]
+        );
+        assert!(injector.resolve_all::<String>().is_empty());
+    }
+
+    #[test]
+    fn test_resolve_all_propagates_contribution_failure() {
+        let injector = Injector::root();
+        injector.provide_multi::<dyn Plugin>(Provider::root(|_| plugin("ok")));
+        injector.provide_multi::<dyn Plugin>(Provider::try_root(|_| {
+            Err::<Shared<dyn Plugin>, _>(ConnectionRefused)
+        }));
+
+        let err = injector.try_resolve_all::<dyn Plugin>().err().unwrap();
+        assert!(err.kind == ErrorKind::FactoryFailed);
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    enum Command {
+        Start,
+        Stop,
+    }
+
+    #[test]
+    fn test_resolve_map_collects_keyed_entries() {
+        let root = Shared::new(Injector::root());
+        root.provide_map_entry::<Command, dyn Plugin>(
+            Command::Start,
+            Provider::singleton(|_| plugin("start")),
+        );
+
+        let child = Injector::child(root.clone());
+        child.provide_map_entry::<Command, dyn Plugin>(
+            Command::Stop,
+            Provider::transient(|_| plugin("stop")),
+        );
+
+        let handlers = child.resolve_map::<Command, dyn Plugin>();
+        assert_eq!(handlers.len(), 2);
+        assert_eq!(handlers[&Command::Start].name(), "start");
+        assert_eq!(handlers[&Command::Stop].name(), "stop");
+
+        assert_eq!(root.resolve_map::<Command, dyn Plugin>().len(), 1);
+        assert!(root.resolve_map::<&str, dyn Plugin>().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_map_key_is_rejected_on_registration() {
+        let injector = Injector::root();
+        injector.provide_map_entry::<&str, dyn Plugin>("run", Provider::root(|_| plugin("a")));
+
+        let err = injector
+            .try_provide_map_entry::<&str, dyn Plugin>("run", Provider::root(|_| plugin("b")))
+            .err()
+            .unwrap();
+        assert!(err.kind == ErrorKind::DuplicateMapKey);
+        assert!(err.message.contains("\"run\""));
+    }
+
+    #[test]
+    fn test_duplicate_map_key_across_injectors_fails_on_resolve() {
+        let root = Shared::new(Injector::root());
+        let child = Injector::child(root.clone());
+
+        child.provide_map_entry::<&str, dyn Plugin>("run", Provider::singleton(|_| plugin("a")));
+        root.provide_map_entry::<&str, dyn Plugin>("run", Provider::singleton(|_| plugin("b")));
+
+        let err = child.try_resolve_map::<&str, dyn Plugin>().err().unwrap();
+        assert!(err.kind == ErrorKind::DuplicateMapKey);
+        assert_eq!(root.resolve_map::<&str, dyn Plugin>().len(), 1);
+    }
+
+    fn circular_chain() -> Vec<String> {
+        vec![
+            std::any::type_name::<ServiceA>().to_string(),
+            std::any::type_name::<ServiceB>().to_string(),
+            std::any::type_name::<ServiceA>().to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_circular_dependency_error_names_the_chain() {
+        let detected = Shared::new(std::sync::Mutex::new(None));
+        let slot = detected.clone();
+
+        let injector = Injector::root();
+        injector.provide::<ServiceA>(Provider::singleton(|injector| {
+            injector.resolve::<ServiceB>();
+            Shared::new(ServiceA)
+        }));
+        injector.provide::<ServiceB>(Provider::singleton(move |injector| {
+            *slot.lock().unwrap() = injector.try_resolve::<ServiceA>().err();
+            Shared::new(ServiceB)
+        }));
+
+        injector.resolve::<ServiceA>();
+
+        let err = detected.lock().unwrap().take().unwrap();
+        assert!(err.kind == ErrorKind::CircularDependency);
+        assert_eq!(err.chain, circular_chain());
+        assert!(err.message.contains(&circular_chain().join(" -> ")));
+    }
+
+    #[tokio::test]
+    async fn test_async_circular_dependency_error_names_the_chain() {
+        let detected = Shared::new(std::sync::Mutex::new(None));
+        let slot = detected.clone();
+
+        let injector = Injector::root();
+        injector.provide::<ServiceA>(Provider::singleton_async(|injector| async move {
+            injector.resolve_async::<ServiceB>().await;
+            Shared::new(ServiceA)
+        }));
+        injector.provide::<ServiceB>(Provider::singleton_async(move |injector| {
+            let slot = slot.clone();
+            async move {
+                let err = injector.try_resolve_async::<ServiceA>().await.err();
+                *slot.lock().unwrap() = err;
+                Shared::new(ServiceB)
+            }
+        }));
+
+        injector.resolve_async::<ServiceA>().await;
+
+        let err = detected.lock().unwrap().take().unwrap();
+        assert!(err.kind == ErrorKind::CircularDependency);
+        assert_eq!(err.chain, circular_chain());
+    }
+
+    #[cfg(feature = "thread-safe")]
+    #[test]
+    fn test_circular_dependency_through_another_thread_is_detected() {
+        let detected = Shared::new(std::sync::Mutex::new(None));
+        let slot = detected.clone();
+
+        let injector = Injector::root();
+        injector.provide::<ServiceA>(Provider::singleton(|injector| {
+            injector.resolve::<ServiceB>();
+            Shared::new(ServiceA)
+        }));
+        injector.provide::<ServiceB>(Provider::singleton(move |injector| {
+            let err = std::thread::scope(|scope| {
+                scope
+                    .spawn(|| injector.try_resolve::<ServiceA>().err())
+                    .join()
+                    .unwrap()
+            });
+            *slot.lock().unwrap() = err;
+            Shared::new(ServiceB)
+        }));
+
+        injector.resolve::<ServiceA>();
+
+        let err = detected.lock().unwrap().take().unwrap();
+        assert!(err.kind == ErrorKind::CircularDependency);
+        assert_eq!(err.chain, circular_chain());
+    }
+
+    #[test]
+    fn test_resolving_the_same_type_from_another_injector_is_not_circular() {
+        let replica = Injector::root();
+        replica.provide::<Database>(Provider::root(|_| {
+            Shared::new(Database {
+                url: "postgres://replica".to_string(),
+            })
+        }));
+
+        let injector = Injector::root();
+        injector.provide::<Database>(Provider::root(move |_| {
+            let url = format!("{},postgres://primary", replica.resolve::<Database>().url);
+            Shared::new(Database { url })
+        }));
+
+        assert_eq!(
+            injector.resolve::<Database>().url,
+            "postgres://replica,postgres://primary"
+        );
+    }
+
+    struct UseCase {
+        #[allow(dead_code)]
+        repository: Shared<dyn UserRepository>,
+    }
+
+    fn type_path(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_missing_provider_error_carries_path() {
+        let injector = Injector::root();
+
+        let err = injector.try_resolve::<Database>().err().unwrap();
+        assert_eq!(err.path, type_path(&[std::any::type_name::<Database>()]));
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    fn test_nested_missing_provider_reports_resolution_path() {
+        let injector = Injector::root();
+        injector.provide::<UseCase>(Provider::try_singleton(|injector| {
+            let repository = injector.try_resolve::<dyn UserRepository>()?;
+            Ok::<_, Error>(Shared::new(UseCase { repository }))
+        }));
+
+        let err = injector.try_resolve::<UseCase>().err().unwrap();
+        assert!(err.kind == ErrorKind::ServiceNotProvided);
+        assert_eq!(
+            err.path,
+            type_path(&[
+                std::any::type_name::<UseCase>(),
+                std::any::type_name::<dyn UserRepository>(),
+            ])
+        );
+        assert!(err.to_string().contains(" -> "));
+    }
+
+    #[cfg(feature = "debug")]
+    #[test]
+    fn test_nested_factory_failure_reports_resolution_path() {
+        let injector = Injector::root();
+        injector.provide::<Database>(Provider::try_root(|_| {
+            Err::<Shared<Database>, _>(ConnectionRefused)
+        }));
+        injector.provide::<Repository>(Provider::try_root(|injector| {
+            let database = injector.try_resolve::<Database>()?;
+            Ok::<_, Error>(Shared::new(Repository {
+                pool: Shared::new(Pool {
+                    id: database.url.len(),
+                }),
+            }))
+        }));
+
+        let err = injector.try_resolve::<Repository>().err().unwrap();
+        assert!(err.kind == ErrorKind::FactoryFailed);
+        assert_eq!(
+            err.path,
+            type_path(&[
+                std::any::type_name::<Repository>(),
+                std::any::type_name::<Database>(),
+            ])
+        );
+    }
+
+    #[cfg(feature = "debug")]
+    #[tokio::test]
+    async fn test_async_resolution_error_carries_path() {
+        let injector = Injector::root();
+        injector.provide::<Repository>(Provider::try_singleton_async(|injector| async move {
+            let pool = injector.try_resolve_async::<Pool>().await?;
+            Ok::<_, Error>(Shared::new(Repository { pool }))
+        }));
+
+        let err = injector
+            .try_resolve_async::<Repository>()
+            .await
+            .err()
+            .unwrap();
+        assert!(err.kind == ErrorKind::ServiceNotProvided);
+        assert_eq!(
+            err.path,
+            type_path(&[
+                std::any::type_name::<Repository>(),
+                std::any::type_name::<Pool>(),
+            ])
+        );
+    }
+
+    type DisposeLog = Shared<std::sync::Mutex<Vec<&'static str>>>;
+
+    struct Tracked {
+        name: &'static str,
+        log: DisposeLog,
+    }
+
+    impl crate::dispose::Dispose for Tracked {
+        fn dispose(&self) -> Result<(), DisposeError> {
+            self.log.lock().unwrap().push(self.name);
+            if self.name.starts_with("failing") {
+                return Err(format!("{} refused to close", self.name).into());
+            }
+            Ok(())
+        }
+    }
+
+    impl crate::dispose::AsyncDispose for Tracked {
+        fn dispose_async(&self) -> BoxFuture<'_, Result<(), DisposeError>> {
+            Box::pin(async move {
+                tokio::task::yield_now().await;
+                self.log.lock().unwrap().push(self.name);
+                Ok(())
+            })
+        }
+    }
+
+    fn tracked(log: &DisposeLog, name: &'static str, scope: Scope) -> Provider<Tracked> {
+        let log = log.clone();
+        let factory = move |_: &Injector| {
+            Shared::new(Tracked {
+                name,
+                log: log.clone(),
+            })
+        };
+
+        match scope {
+            Scope::Root => Provider::root(factory),
+            Scope::Module => Provider::singleton(factory),
+            Scope::Transient => Provider::transient(factory),
+            Scope::Scoped => Provider::scoped(factory),
+        }
+        .with_dispose()
+    }
+
+    #[test]
+    fn test_dispose_runs_in_reverse_creation_order() {
+        let log = DisposeLog::default();
+        let injector = Injector::root();
+        injector.provide_named::<Tracked>("first", tracked(&log, "first", Scope::Root));
+        injector.provide_named::<Tracked>("second", tracked(&log, "second", Scope::Module));
+        injector
+            .provide_named::<Tracked>("transient", tracked(&log, "transient", Scope::Transient));
+
+        injector.resolve_named::<Tracked>("second");
+        injector.resolve_named::<Tracked>("transient");
+        injector.resolve_named::<Tracked>("first");
+
+        injector.dispose().unwrap();
+        assert_eq!(*log.lock().unwrap(), ["first", "second"]);
+
+        injector.dispose().unwrap();
+        assert_eq!(log.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_dispose_releases_cached_instances() {
+        let log = DisposeLog::default();
+        let injector = Injector::root();
+        injector.provide::<Tracked>(tracked(&log, "pool", Scope::Root));
+
+        let before = injector.resolve::<Tracked>();
+        injector.dispose().unwrap();
+        let after = injector.resolve::<Tracked>();
+
+        assert!(!Shared::ptr_eq(&before, &after));
+    }
+
+    #[test]
+    fn test_dispose_collects_every_failure() {
+        let log = DisposeLog::default();
+        let injector = Injector::root();
+        injector.provide_named::<Tracked>("a", tracked(&log, "failing-a", Scope::Root));
+        injector.provide_named::<Tracked>("b", tracked(&log, "ok", Scope::Root));
+        injector.provide_named::<Tracked>("c", tracked(&log, "failing-c", Scope::Root));
+
+        for name in ["a", "b", "c"] {
+            injector.resolve_named::<Tracked>(name);
+        }
+
+        let err = injector.dispose().unwrap_err();
+        assert_eq!(*log.lock().unwrap(), ["failing-c", "ok", "failing-a"]);
+        assert_eq!(err.errors.len(), 2);
+        assert!(
+            err.errors
+                .iter()
+                .all(|error| error.kind == ErrorKind::DisposeFailed)
+        );
+        assert_eq!(
+            err.errors[0].source.as_ref().unwrap().to_string(),
+            "failing-c refused to close"
+        );
+    }
+
+    #[test]
+    fn test_dispose_tracks_multi_binding_contributions() {
+        let log = DisposeLog::default();
+        let root = Shared::new(Injector::root());
+        let child = Injector::child(root.clone());
+        child.provide_multi::<Tracked>(tracked(&log, "plugin", Scope::Module));
+
+        assert_eq!(child.resolve_all::<Tracked>().len(), 1);
+
+        root.dispose().unwrap();
+        assert!(log.lock().unwrap().is_empty());
+
+        child.dispose().unwrap();
+        assert_eq!(*log.lock().unwrap(), ["plugin"]);
+    }
+
+    #[tokio::test]
+    async fn test_async_dispose_hooks_need_dispose_async() {
+        let log = DisposeLog::default();
+        let injector = Injector::root();
+        let provider_log = log.clone();
+        injector.provide::<Tracked>(
+            Provider::root(move |_| {
+                Shared::new(Tracked {
+                    name: "async",
+                    log: provider_log.clone(),
+                })
+            })
+            .with_async_dispose(),
+        );
+        injector.provide_named::<Tracked>("sync", tracked(&log, "sync", Scope::Root));
+
+        injector.resolve::<Tracked>();
+        injector.resolve_named::<Tracked>("sync");
+        let err = injector.dispose().unwrap_err();
+        assert!(err.errors[0].message.contains("dispose_async"));
+
+        injector.resolve::<Tracked>();
+        injector.resolve_named::<Tracked>("sync");
+        injector.dispose_async().await.unwrap();
+        assert_eq!(*log.lock().unwrap(), ["sync", "sync", "async"]);
+    }
+
+    #[test]
+    fn test_eager_providers_are_instantiated_once() {
+        let counter = Shared::new(std::sync::Mutex::new(0));
+        let injector = Injector::root();
+
+        let singleton_counter = counter.clone();
+        injector.provide::<u32>(
+            Provider::singleton(move |_| {
+                *singleton_counter.lock().unwrap() += 1;
+                Shared::new(7)
+            })
+            .eager(),
+        );
+        let transient_counter = counter.clone();
+        injector.provide::<u64>(
+            Provider::transient(move |_| {
+                *transient_counter.lock().unwrap() += 1;
+                Shared::new(8)
+            })
+            .eager(),
+        );
+
+        let mut errors = Vec::new();
+        injector.instantiate_eager_into(&mut errors);
+        injector.instantiate_eager_into(&mut errors);
+
+        assert!(errors.is_empty());
+        assert_eq!(*counter.lock().unwrap(), 1);
+        assert!(injector.get_cached(&Key::of::<u32>()).is_some());
+
+        injector.resolve::<u32>();
+        assert_eq!(*counter.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_scoped_instances_are_cached_per_scope() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::scoped(|_| Shared::new(Pool { id: 1 })));
+
+        let first = injector.create_scope();
+        let second = injector.create_scope();
+
+        let pool = first.resolve::<Pool>();
+        assert!(Shared::ptr_eq(&pool, &first.resolve::<Pool>()));
+        assert!(!Shared::ptr_eq(&pool, &second.resolve::<Pool>()));
+    }
+
+    #[test]
+    fn test_scoped_instances_are_disposed_with_their_scope() {
+        let log = DisposeLog::default();
+        let injector = Injector::root();
+        injector.provide::<Tracked>(tracked(&log, "request", Scope::Scoped));
+
+        let scope = injector.create_scope();
+        scope.resolve::<Tracked>();
+        scope.resolve::<Tracked>();
+        assert!(log.lock().unwrap().is_empty());
+
+        drop(scope);
+        assert_eq!(*log.lock().unwrap(), ["request"]);
+    }
+
+    #[test]
+    fn test_scoped_service_requires_a_scope() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::scoped(|_| Shared::new(Pool { id: 1 })));
+
+        let err = injector.try_resolve::<Pool>().err().unwrap();
+        assert!(err.kind == ErrorKind::NoActiveScope);
+    }
+
+    #[test]
+    fn test_transient_dependencies_share_the_scope() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::scoped(|_| Shared::new(Pool { id: 1 })));
+        injector.provide::<Repository>(Provider::transient(|injector| {
+            Shared::new(Repository {
+                pool: injector.resolve::<Pool>(),
+            })
+        }));
+
+        let scope = injector.create_scope();
+        let repository = scope.resolve::<Repository>();
+        assert!(Shared::ptr_eq(&repository.pool, &scope.resolve::<Pool>()));
+    }
+
+    #[test]
+    fn test_singleton_capturing_scoped_service_is_rejected() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::scoped(|_| Shared::new(Pool { id: 1 })));
+        injector.provide::<Repository>(Provider::try_root(|injector| {
+            injector
+                .try_resolve::<Pool>()
+                .map(|pool| Shared::new(Repository { pool }))
+        }));
+
+        let scope = injector.create_scope();
+        scope.resolve::<Pool>();
+
+        let err = scope.try_resolve::<Repository>().err().unwrap();
+        assert!(err.kind == ErrorKind::CaptiveDependency);
+        assert!(err.message.contains("Repository (Root scope)"));
+    }
+
+    #[tokio::test]
+    async fn test_async_scoped_instances_are_cached_per_scope() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::scoped_async(|_| async {
+            tokio::task::yield_now().await;
+            Shared::new(Pool { id: 2 })
+        }));
+        injector.provide::<Repository>(Provider::try_singleton_async(|injector| async move {
+            let pool = injector.try_resolve_async::<Pool>().await?;
+            Ok::<_, Error>(Shared::new(Repository { pool }))
+        }));
+
+        let scope = injector.create_scope();
+        let pool = scope.resolve_async::<Pool>().await;
+        assert!(Shared::ptr_eq(&pool, &scope.resolve_async::<Pool>().await));
+
+        let err = injector
+            .create_scope()
+            .try_resolve_async::<Repository>()
+            .await;
+        assert!(err.err().unwrap().kind == ErrorKind::CaptiveDependency);
+    }
+
+    fn repository_depending_on(scope: Scope) -> Injector {
+        let injector = Injector::root();
+        let pool = |_: &Injector| Shared::new(Pool { id: 1 });
+        injector.provide::<Pool>(match scope {
+            Scope::Root => Provider::root(pool),
+            Scope::Module => Provider::singleton(pool),
+            Scope::Transient => Provider::transient(pool),
+            Scope::Scoped => Provider::scoped(pool),
+        });
+        injector.provide::<Repository>(Provider::try_root(|injector| {
+            injector
+                .try_resolve::<Pool>()
+                .map(|pool| Shared::new(Repository { pool }))
+        }));
+        injector
+    }
+
+    #[test]
+    fn test_longer_lived_service_capturing_shorter_lived_one_is_rejected() {
+        for scope in [Scope::Module, Scope::Transient] {
+            let injector = repository_depending_on(scope);
+
+            let err = injector.try_resolve::<Repository>().err().unwrap();
+            assert!(err.kind == ErrorKind::CaptiveDependency);
+            assert!(err.message.contains("Repository (Root scope)"));
+            assert!(err.message.contains(&format!("Pool ({} scope)", scope)));
+        }
+
+        repository_depending_on(Scope::Root).resolve::<Repository>();
+    }
+
+    #[test]
+    fn test_captive_dependency_is_detected_when_already_cached() {
+        let injector = repository_depending_on(Scope::Module);
+        injector.resolve::<Pool>();
+
+        let err = injector.try_resolve::<Repository>().err().unwrap();
+        assert!(err.kind == ErrorKind::CaptiveDependency);
+    }
+
+    #[test]
+    fn test_captive_policy_can_allow_captive_dependencies() {
+        for policy in [CaptivePolicy::Warn, CaptivePolicy::Allow] {
+            let injector = repository_depending_on(Scope::Transient);
+            Injector::child(Shared::new(injector.clone())).set_captive_policy(policy);
+
+            assert!(injector.captive_policy() == policy);
+            assert_eq!(injector.resolve::<Repository>().pool.id, 1);
+        }
+    }
+
+    #[test]
+    fn test_captive_multi_binding_contribution_is_rejected() {
+        let injector = Injector::root();
+        injector.provide_multi::<Pool>(Provider::transient(|_| Shared::new(Pool { id: 1 })));
+        injector.provide::<Repository>(Provider::try_singleton(|injector| {
+            injector.try_resolve_all::<Pool>().map(|mut pools| {
+                Shared::new(Repository {
+                    pool: pools.remove(0),
+                })
+            })
+        }));
+
+        let err = injector.try_resolve::<Repository>().err().unwrap();
+        assert!(err.kind == ErrorKind::CaptiveDependency);
+    }
+
+    #[tokio::test]
+    async fn test_async_captive_dependency_is_rejected() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::transient_async(|_| async {
+            Shared::new(Pool { id: 1 })
+        }));
+        injector.provide::<Repository>(Provider::try_root_async(|injector| async move {
+            let pool = injector.try_resolve_async::<Pool>().await?;
+            Ok::<_, Error>(Shared::new(Repository { pool }))
+        }));
+
+        let err = injector
+            .try_resolve_async::<Repository>()
+            .await
+            .err()
+            .unwrap();
+        assert!(err.kind == ErrorKind::CaptiveDependency);
+    }
+
+    #[test]
+    fn test_root_factory_runs_with_root_injector() {
+        let injector = repository_depending_on(Scope::Root);
+        let child = Injector::child(Shared::new(injector.clone()));
+        child.provide::<Pool>(Provider::singleton(|_| Shared::new(Pool { id: 2 })));
+
+        assert_eq!(child.resolve::<Pool>().id, 2);
+
+        let repository = child.resolve::<Repository>();
+        assert_eq!(repository.pool.id, 1);
+        assert!(Shared::ptr_eq(
+            &repository,
+            &injector.resolve::<Repository>()
+        ));
+    }
+
+    #[test]
+    fn test_module_singleton_is_cached_where_registered() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::singleton(|_| Shared::new(Pool { id: 1 })));
+
+        let first = Injector::child(Shared::new(injector.clone()));
+        let second = Injector::child(Shared::new(injector.clone()));
+
+        // Each child used to cache its own instance; both now share the one
+        // cached next to the provider.
+        let pool = first.resolve::<Pool>();
+        assert!(Shared::ptr_eq(&pool, &second.resolve::<Pool>()));
+        assert!(Shared::ptr_eq(&pool, &injector.resolve::<Pool>()));
+
+        assert!(injector.get_cached(&Key::of::<Pool>()).is_some());
+        assert!(first.get_cached(&Key::of::<Pool>()).is_none());
+        assert!(second.get_cached(&Key::of::<Pool>()).is_none());
+    }
+
+    #[test]
+    fn test_module_singletons_registered_in_each_injector_stay_distinct() {
+        let injector = Injector::root();
+        injector.provide::<Pool>(Provider::singleton(|_| Shared::new(Pool { id: 1 })));
+        let child = Injector::child(Shared::new(injector.clone()));
+        child.provide::<Pool>(Provider::singleton(|_| Shared::new(Pool { id: 2 })));
+
+        // The parent's cached instance used to shadow the child's provider.
+        assert_eq!(injector.resolve::<Pool>().id, 1);
+        assert_eq!(child.resolve::<Pool>().id, 2);
+    }
+
+    #[test]
+    fn test_frozen_index_owner_is_missing_once_dropped() {
+        let owner = Shared::new(Injector::root());
+        owner.provide::<u32>(Provider::singleton(|_| Shared::new(1)));
+
+        let indexed = Owner {
+            injector: Shared::downgrade(&owner.inner),
+            provider: owner.get_provider(&Key::of::<u32>()).unwrap(),
+        };
+        assert!(indexed.locate().is_some());
+
+        drop(owner);
+        assert!(indexed.locate().is_none());
     }
 }
@@ -1,14 +1,18 @@
 pub mod application;
+pub mod dispose;
 pub mod error;
 pub mod injector;
 pub mod instance;
 pub mod module;
+mod pending;
 pub mod provider;
 pub mod resolve_guard;
 pub mod runtime;
 pub mod scope;
+mod table;
 
 pub use application::*;
+pub use dispose::*;
 pub use error::*;
 pub use injector::*;
 pub use instance::*;
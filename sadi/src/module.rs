@@ -29,7 +29,151 @@
 //!     }
 //! }
 //! ```
-use crate::injector::Injector;
+use std::any::TypeId;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::error::Error;
+use crate::injector::{Injector, Key};
+use crate::provider::Provider;
+use crate::runtime::Shared;
+
+#[cfg(not(feature = "thread-safe"))]
+type RegisterOptions = Box<dyn Fn(&Injector) -> Result<(), Error>>;
+#[cfg(feature = "thread-safe")]
+type RegisterOptions = Box<dyn Fn(&Injector) -> Result<(), Error> + Send + Sync>;
+
+/// A provider a module publishes to the module importing it.
+///
+/// Providers registered by a module live in its own child injector and stay
+/// private unless listed in [`Module::exports`]. An exported provider is
+/// resolved by the importer (and by the importer's other imports) from the
+/// exporting module's injector, so every consumer shares the same instance.
+///
+/// # Examples
+///
+/// ```
+/// use sadi::module::Export;
+///
+/// let repository = Export::of::<String>();
+/// let replica = Export::named::<String>("replica");
+/// # let _ = (repository, replica);
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Export {
+    pub(crate) key: Key,
+}
+
+impl Export {
+    /// Exports the unnamed provider of `T`.
+    pub fn of<T: ?Sized + 'static>() -> Self {
+        Self {
+            key: Key::of::<T>(),
+        }
+    }
+
+    /// Exports the provider of `T` registered under `name`.
+    pub fn named<T: ?Sized + 'static>(name: impl Into<String>) -> Self {
+        Self {
+            key: Key::named::<T>(&name.into()),
+        }
+    }
+}
+
+/// The options a configured module was built with.
+///
+/// Returned by [`Module::options`]. When the module is loaded, the value is
+/// registered as a module-scoped provider of `T` in the module's injector, so
+/// its own providers can resolve it. Two instances of the same module type are
+/// loaded separately when their options differ, and the options' `Debug`
+/// output is shown next to the module in bootstrap errors.
+///
+/// # Examples
+///
+/// ```
+/// use sadi::module::ModuleOptions;
+///
+/// #[derive(Clone, Debug, Hash)]
+/// struct DbOptions {
+///     url: String,
+///     pool_size: usize,
+/// }
+///
+/// let options = ModuleOptions::new(DbOptions {
+///     url: "sqlite::memory:".to_string(),
+///     pool_size: 4,
+/// });
+/// assert!(options.description().contains("pool_size: 4"));
+/// ```
+pub struct ModuleOptions {
+    pub(crate) hash: u64,
+    description: String,
+    register: RegisterOptions,
+}
+
+impl ModuleOptions {
+    /// Returns the `Debug` representation of the options.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Registers the options value in the module's injector.
+    pub(crate) fn register(&self, injector: &Injector) -> Result<(), Error> {
+        (self.register)(injector)
+    }
+
+    fn hash_of<T: Hash + 'static>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        TypeId::of::<T>().hash(&mut hasher);
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(not(feature = "thread-safe"))]
+impl ModuleOptions {
+    /// Wraps the options value of a configured module.
+    pub fn new<T>(value: T) -> Self
+    where
+        T: Clone + Hash + std::fmt::Debug + 'static,
+    {
+        Self {
+            hash: Self::hash_of(&value),
+            description: format!("{:?}", value),
+            register: Box::new(move |injector| {
+                let value = value.clone();
+                injector.try_provide::<T>(Provider::singleton(move |_| Shared::new(value.clone())))
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "thread-safe")]
+impl ModuleOptions {
+    /// Wraps the options value of a configured module.
+    pub fn new<T>(value: T) -> Self
+    where
+        T: Clone + Hash + std::fmt::Debug + Send + Sync + 'static,
+    {
+        Self {
+            hash: Self::hash_of(&value),
+            description: format!("{:?}", value),
+            register: Box::new(move |injector| {
+                let value = value.clone();
+                injector.try_provide::<T>(Provider::singleton(move |_| Shared::new(value.clone())))
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "debug")]
+impl std::fmt::Debug for ModuleOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModuleOptions")
+            .field("description", &self.description)
+            .finish()
+    }
+}
 
 /// Trait for defining a module in the dependency injection system.
 ///
@@ -50,6 +194,13 @@ use crate::injector::Injector;
 /// # Optional Methods
 ///
 /// - [`imports`](Module::imports): Returns other modules that this module depends on
+/// - [`try_providers`](Module::try_providers): Fallible variant of `providers`
+/// - [`exports`](Module::exports): Providers visible to the importing module
+/// - [`options`](Module::options): Options of a configured module
+/// - [`is_global`](Module::is_global): Makes the module's providers visible everywhere
+/// - [`is_lazy`](Module::is_lazy): Defers loading the module until it is used
+/// - [`on_init`](Module::on_init), [`on_bootstrap`](Module::on_bootstrap),
+///   [`on_shutdown`](Module::on_shutdown): Lifecycle hooks
 ///
 /// # Examples
 ///
@@ -207,6 +358,154 @@ pub trait Module {
         vec![]
     }
 
+    /// Returns the options this module instance was configured with.
+    ///
+    /// A module type is normally loaded once per application. Configured
+    /// modules, such as `DatabaseModule::with_options(..)` or
+    /// `CacheModule::for_feature("users")`, return their options here so that
+    /// instances with different options are loaded separately, while
+    /// instances with equal options are still shared. The value is registered
+    /// as a provider in the module's injector before
+    /// [`providers`](Module::providers) runs.
+    ///
+    /// # Default Implementation
+    ///
+    /// By default, returns `None` (the module is not configurable).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Module, ModuleOptions, Provider, Shared};
+    ///
+    /// #[derive(Clone, Debug, Hash)]
+    /// struct CacheFeature(&'static str);
+    ///
+    /// struct Cache {
+    ///     prefix: String,
+    /// }
+    ///
+    /// struct CacheModule {
+    ///     feature: CacheFeature,
+    /// }
+    ///
+    /// impl CacheModule {
+    ///     fn for_feature(name: &'static str) -> Self {
+    ///         Self { feature: CacheFeature(name) }
+    ///     }
+    /// }
+    ///
+    /// impl Module for CacheModule {
+    ///     fn options(&self) -> Option<ModuleOptions> {
+    ///         Some(ModuleOptions::new(self.feature.clone()))
+    ///     }
+    ///
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Cache>(Provider::singleton(|injector| {
+    ///             let feature = injector.resolve::<CacheFeature>();
+    ///             Shared::new(Cache { prefix: feature.0.to_string() })
+    ///         }));
+    ///     }
+    /// }
+    ///
+    /// let module = CacheModule::for_feature("users");
+    /// assert_eq!(module.options().unwrap().description(), "CacheFeature(\"users\")");
+    /// ```
+    fn options(&self) -> Option<ModuleOptions> {
+        None
+    }
+
+    /// Returns whether this module's providers are visible from every module.
+    ///
+    /// Providers of a global module resolve from any injector of the
+    /// application, as if every module had imported it, without having to be
+    /// registered with [`Provider::root`]. They keep their own scope: a
+    /// `Module`-scoped singleton is still owned and cached by the global
+    /// module's injector. A global module still has to be imported once,
+    /// typically by the root module.
+    ///
+    /// # Default Implementation
+    ///
+    /// By default, returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Injector, Module, Provider, Shared};
+    ///
+    /// struct Clock;
+    ///
+    /// struct ClockModule;
+    /// impl Module for ClockModule {
+    ///     fn is_global(&self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Clock>(Provider::singleton(|_| Shared::new(Clock)));
+    ///     }
+    /// }
+    ///
+    /// struct AppModule;
+    /// impl Module for AppModule {
+    ///     fn imports(&self) -> Vec<Box<dyn Module>> {
+    ///         vec![Box::new(ClockModule)]
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(AppModule);
+    /// app.bootstrap();
+    /// assert!(app.injector().try_resolve::<Clock>().is_ok());
+    /// ```
+    fn is_global(&self) -> bool {
+        false
+    }
+
+    /// Returns whether this module is loaded on first use instead of at bootstrap.
+    ///
+    /// A lazy module's [`exports`](Module::exports) act as its manifest: at
+    /// bootstrap only the exports are published to the importer. The first
+    /// resolution of one of them loads the module's imports, registers its
+    /// providers and runs its [`on_init`](Module::on_init) (and, if the
+    /// application is already bootstrapped, [`on_bootstrap`](Module::on_bootstrap))
+    /// hooks. Failures are then returned by that resolution with
+    /// [`ErrorKind::ModuleLoadFailed`](crate::error::ErrorKind::ModuleLoadFailed).
+    ///
+    /// # Default Implementation
+    ///
+    /// By default, returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Export, Injector, Module, Provider, Shared};
+    ///
+    /// struct ReportPrinter;
+    ///
+    /// struct ReportsModule;
+    /// impl Module for ReportsModule {
+    ///     fn is_lazy(&self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn exports(&self) -> Vec<Export> {
+    ///         vec![Export::of::<ReportPrinter>()]
+    ///     }
+    ///
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<ReportPrinter>(Provider::singleton(|_| Shared::new(ReportPrinter)));
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(ReportsModule);
+    /// app.bootstrap();
+    ///
+    /// // `ReportsModule::providers` runs here
+    /// assert!(app.injector().try_resolve::<ReportPrinter>().is_ok());
+    /// ```
+    fn is_lazy(&self) -> bool {
+        false
+    }
+
     /// Registers providers with the given injector.
     ///
     /// This method is called to configure the dependency injection container with
@@ -233,6 +532,153 @@ pub trait Module {
     /// }
     /// ```
     fn providers(&self, _injector: &Injector) {}
+
+    /// Registers providers, reporting failures instead of panicking.
+    ///
+    /// [`Application::try_bootstrap`](crate::application::Application::try_bootstrap)
+    /// calls this method and collects the returned error, tagged with the
+    /// module's [`type_name`](Module::type_name), while it keeps loading the rest
+    /// of the import tree.
+    ///
+    /// # Default Implementation
+    ///
+    /// Calls [`providers`](Module::providers) and returns `Ok(())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Error, Injector, Module, Provider, Shared};
+    ///
+    /// struct ConfigModule;
+    ///
+    /// impl Module for ConfigModule {
+    ///     fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+    ///         injector.try_provide::<String>(Provider::root(|_| Shared::new("prod".to_string())))?;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let injector = Injector::root();
+    /// assert!(ConfigModule.try_providers(&injector).is_ok());
+    /// assert!(ConfigModule.try_providers(&injector).is_err());
+    /// ```
+    fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+        self.providers(injector);
+        Ok(())
+    }
+
+    /// Returns the providers this module publishes to its importer.
+    ///
+    /// Everything else the module registers stays private to its own
+    /// injector. Importers may list an imported module's export in their own
+    /// `exports` to re-export it further up the tree. Exporting a type the
+    /// module does not provide is reported by
+    /// [`Application::try_bootstrap`](crate::application::Application::try_bootstrap).
+    ///
+    /// # Default Implementation
+    ///
+    /// By default, returns an empty vector (nothing is exported).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Export, Injector, Module, Provider, Shared};
+    ///
+    /// struct Repository;
+    ///
+    /// struct RepositoryModule;
+    /// impl Module for RepositoryModule {
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Repository>(Provider::singleton(|_| Shared::new(Repository)));
+    ///     }
+    ///
+    ///     fn exports(&self) -> Vec<Export> {
+    ///         vec![Export::of::<Repository>()]
+    ///     }
+    /// }
+    ///
+    /// struct AppModule;
+    /// impl Module for AppModule {
+    ///     fn imports(&self) -> Vec<Box<dyn Module>> {
+    ///         vec![Box::new(RepositoryModule)]
+    ///     }
+    ///
+    ///     fn exports(&self) -> Vec<Export> {
+    ///         vec![Export::of::<Repository>()]
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(AppModule);
+    /// app.bootstrap();
+    /// assert!(app.injector().try_resolve::<Repository>().is_ok());
+    /// ```
+    fn exports(&self) -> Vec<Export> {
+        vec![]
+    }
+
+    /// Runs right after this module's providers are registered.
+    ///
+    /// Imports are initialized before the modules importing them, so the
+    /// injector can already resolve everything this module and its imports
+    /// provide. An error is reported by
+    /// [`Application::try_bootstrap`](crate::application::Application::try_bootstrap).
+    ///
+    /// # Parameters
+    ///
+    /// - `injector`: The module's own child injector
+    fn on_init(&self, _injector: &Injector) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Runs once the whole application has bootstrapped.
+    ///
+    /// Every module is loaded at this point, so the hook can resolve services
+    /// registered by other modules, for instance to run migrations. Hooks run
+    /// in load order, imports first.
+    ///
+    /// # Parameters
+    ///
+    /// - `injector`: The module's own child injector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Error, Injector, Module, Provider, Shared};
+    ///
+    /// struct Migrations;
+    ///
+    /// struct DatabaseModule;
+    ///
+    /// impl Module for DatabaseModule {
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Migrations>(Provider::root(|_| Shared::new(Migrations)));
+    ///     }
+    ///
+    ///     fn on_bootstrap(&self, injector: &Injector) -> Result<(), Error> {
+    ///         let _migrations = injector.try_resolve::<Migrations>()?;
+    ///         // Run pending migrations
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(DatabaseModule);
+    /// assert!(app.try_bootstrap().is_ok());
+    /// ```
+    fn on_bootstrap(&self, _injector: &Injector) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Runs during [`Application::shutdown`](crate::application::Application::shutdown).
+    ///
+    /// Modules are shut down in reverse load order, and each hook runs before
+    /// the singletons cached in the module's injector are disposed.
+    ///
+    /// # Parameters
+    ///
+    /// - `injector`: The module's own child injector
+    fn on_shutdown(&self, _injector: &Injector) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 #[cfg(feature = "thread-safe")]
@@ -338,6 +784,154 @@ pub trait Module: Send + Sync {
         vec![]
     }
 
+    /// Returns the options this module instance was configured with.
+    ///
+    /// A module type is normally loaded once per application. Configured
+    /// modules, such as `DatabaseModule::with_options(..)` or
+    /// `CacheModule::for_feature("users")`, return their options here so that
+    /// instances with different options are loaded separately, while
+    /// instances with equal options are still shared. The value is registered
+    /// as a provider in the module's injector before
+    /// [`providers`](Module::providers) runs.
+    ///
+    /// # Default Implementation
+    ///
+    /// By default, returns `None` (the module is not configurable).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Module, ModuleOptions, Provider, Shared};
+    ///
+    /// #[derive(Clone, Debug, Hash)]
+    /// struct CacheFeature(&'static str);
+    ///
+    /// struct Cache {
+    ///     prefix: String,
+    /// }
+    ///
+    /// struct CacheModule {
+    ///     feature: CacheFeature,
+    /// }
+    ///
+    /// impl CacheModule {
+    ///     fn for_feature(name: &'static str) -> Self {
+    ///         Self { feature: CacheFeature(name) }
+    ///     }
+    /// }
+    ///
+    /// impl Module for CacheModule {
+    ///     fn options(&self) -> Option<ModuleOptions> {
+    ///         Some(ModuleOptions::new(self.feature.clone()))
+    ///     }
+    ///
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Cache>(Provider::singleton(|injector| {
+    ///             let feature = injector.resolve::<CacheFeature>();
+    ///             Shared::new(Cache { prefix: feature.0.to_string() })
+    ///         }));
+    ///     }
+    /// }
+    ///
+    /// let module = CacheModule::for_feature("users");
+    /// assert_eq!(module.options().unwrap().description(), "CacheFeature(\"users\")");
+    /// ```
+    fn options(&self) -> Option<ModuleOptions> {
+        None
+    }
+
+    /// Returns whether this module's providers are visible from every module.
+    ///
+    /// Providers of a global module resolve from any injector of the
+    /// application, as if every module had imported it, without having to be
+    /// registered with [`Provider::root`]. They keep their own scope: a
+    /// `Module`-scoped singleton is still owned and cached by the global
+    /// module's injector. A global module still has to be imported once,
+    /// typically by the root module.
+    ///
+    /// # Default Implementation
+    ///
+    /// By default, returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Injector, Module, Provider, Shared};
+    ///
+    /// struct Clock;
+    ///
+    /// struct ClockModule;
+    /// impl Module for ClockModule {
+    ///     fn is_global(&self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Clock>(Provider::singleton(|_| Shared::new(Clock)));
+    ///     }
+    /// }
+    ///
+    /// struct AppModule;
+    /// impl Module for AppModule {
+    ///     fn imports(&self) -> Vec<Box<dyn Module>> {
+    ///         vec![Box::new(ClockModule)]
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(AppModule);
+    /// app.bootstrap();
+    /// assert!(app.injector().try_resolve::<Clock>().is_ok());
+    /// ```
+    fn is_global(&self) -> bool {
+        false
+    }
+
+    /// Returns whether this module is loaded on first use instead of at bootstrap.
+    ///
+    /// A lazy module's [`exports`](Module::exports) act as its manifest: at
+    /// bootstrap only the exports are published to the importer. The first
+    /// resolution of one of them loads the module's imports, registers its
+    /// providers and runs its [`on_init`](Module::on_init) (and, if the
+    /// application is already bootstrapped, [`on_bootstrap`](Module::on_bootstrap))
+    /// hooks. Failures are then returned by that resolution with
+    /// [`ErrorKind::ModuleLoadFailed`](crate::error::ErrorKind::ModuleLoadFailed).
+    ///
+    /// # Default Implementation
+    ///
+    /// By default, returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Export, Injector, Module, Provider, Shared};
+    ///
+    /// struct ReportPrinter;
+    ///
+    /// struct ReportsModule;
+    /// impl Module for ReportsModule {
+    ///     fn is_lazy(&self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn exports(&self) -> Vec<Export> {
+    ///         vec![Export::of::<ReportPrinter>()]
+    ///     }
+    ///
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<ReportPrinter>(Provider::singleton(|_| Shared::new(ReportPrinter)));
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(ReportsModule);
+    /// app.bootstrap();
+    ///
+    /// // `ReportsModule::providers` runs here
+    /// assert!(app.injector().try_resolve::<ReportPrinter>().is_ok());
+    /// ```
+    fn is_lazy(&self) -> bool {
+        false
+    }
+
     /// Registers providers with the given injector.
     ///
     /// This method is called to configure the dependency injection container with
@@ -364,6 +958,153 @@ pub trait Module: Send + Sync {
     /// }
     /// ```
     fn providers(&self, _injector: &Injector) {}
+
+    /// Registers providers, reporting failures instead of panicking.
+    ///
+    /// [`Application::try_bootstrap`](crate::application::Application::try_bootstrap)
+    /// calls this method and collects the returned error, tagged with the
+    /// module's [`type_name`](Module::type_name), while it keeps loading the rest
+    /// of the import tree.
+    ///
+    /// # Default Implementation
+    ///
+    /// Calls [`providers`](Module::providers) and returns `Ok(())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Error, Injector, Module, Provider, Shared};
+    ///
+    /// struct ConfigModule;
+    ///
+    /// impl Module for ConfigModule {
+    ///     fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+    ///         injector.try_provide::<String>(Provider::root(|_| Shared::new("prod".to_string())))?;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let injector = Injector::root();
+    /// assert!(ConfigModule.try_providers(&injector).is_ok());
+    /// assert!(ConfigModule.try_providers(&injector).is_err());
+    /// ```
+    fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+        self.providers(injector);
+        Ok(())
+    }
+
+    /// Returns the providers this module publishes to its importer.
+    ///
+    /// Everything else the module registers stays private to its own
+    /// injector. Importers may list an imported module's export in their own
+    /// `exports` to re-export it further up the tree. Exporting a type the
+    /// module does not provide is reported by
+    /// [`Application::try_bootstrap`](crate::application::Application::try_bootstrap).
+    ///
+    /// # Default Implementation
+    ///
+    /// By default, returns an empty vector (nothing is exported).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Export, Injector, Module, Provider, Shared};
+    ///
+    /// struct Repository;
+    ///
+    /// struct RepositoryModule;
+    /// impl Module for RepositoryModule {
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Repository>(Provider::singleton(|_| Shared::new(Repository)));
+    ///     }
+    ///
+    ///     fn exports(&self) -> Vec<Export> {
+    ///         vec![Export::of::<Repository>()]
+    ///     }
+    /// }
+    ///
+    /// struct AppModule;
+    /// impl Module for AppModule {
+    ///     fn imports(&self) -> Vec<Box<dyn Module>> {
+    ///         vec![Box::new(RepositoryModule)]
+    ///     }
+    ///
+    ///     fn exports(&self) -> Vec<Export> {
+    ///         vec![Export::of::<Repository>()]
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(AppModule);
+    /// app.bootstrap();
+    /// assert!(app.injector().try_resolve::<Repository>().is_ok());
+    /// ```
+    fn exports(&self) -> Vec<Export> {
+        vec![]
+    }
+
+    /// Runs right after this module's providers are registered.
+    ///
+    /// Imports are initialized before the modules importing them, so the
+    /// injector can already resolve everything this module and its imports
+    /// provide. An error is reported by
+    /// [`Application::try_bootstrap`](crate::application::Application::try_bootstrap).
+    ///
+    /// # Parameters
+    ///
+    /// - `injector`: The module's own child injector
+    fn on_init(&self, _injector: &Injector) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Runs once the whole application has bootstrapped.
+    ///
+    /// Every module is loaded at this point, so the hook can resolve services
+    /// registered by other modules, for instance to run migrations. Hooks run
+    /// in load order, imports first.
+    ///
+    /// # Parameters
+    ///
+    /// - `injector`: The module's own child injector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Application, Error, Injector, Module, Provider, Shared};
+    ///
+    /// struct Migrations;
+    ///
+    /// struct DatabaseModule;
+    ///
+    /// impl Module for DatabaseModule {
+    ///     fn providers(&self, injector: &Injector) {
+    ///         injector.provide::<Migrations>(Provider::root(|_| Shared::new(Migrations)));
+    ///     }
+    ///
+    ///     fn on_bootstrap(&self, injector: &Injector) -> Result<(), Error> {
+    ///         let _migrations = injector.try_resolve::<Migrations>()?;
+    ///         // Run pending migrations
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut app = Application::new(DatabaseModule);
+    /// assert!(app.try_bootstrap().is_ok());
+    /// ```
+    fn on_bootstrap(&self, _injector: &Injector) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Runs during [`Application::shutdown`](crate::application::Application::shutdown).
+    ///
+    /// Modules are shut down in reverse load order, and each hook runs before
+    /// the singletons cached in the module's injector are disposed.
+    ///
+    /// # Parameters
+    ///
+    /// - `injector`: The module's own child injector
+    fn on_shutdown(&self, _injector: &Injector) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -515,4 +1256,42 @@ mod tests {
             assert_eq!(*module.call_count.lock().unwrap(), 2);
         }
     }
+
+    struct DuplicateModule;
+
+    impl Module for DuplicateModule {
+        fn try_providers(&self, injector: &Injector) -> Result<(), Error> {
+            injector.try_provide::<u8>(crate::provider::Provider::root(|_| {
+                crate::runtime::Shared::new(1)
+            }))
+        }
+    }
+
+    #[test]
+    fn test_try_providers_reports_registration_errors() {
+        let injector = Injector::root();
+
+        assert!(DuplicateModule.try_providers(&injector).is_ok());
+        assert!(DuplicateModule.try_providers(&injector).is_err());
+    }
+
+    #[test]
+    fn test_default_try_providers_calls_providers() {
+        #[cfg(not(feature = "thread-safe"))]
+        let module = CountingModule {
+            call_count: std::cell::RefCell::new(0),
+        };
+
+        #[cfg(feature = "thread-safe")]
+        let module = CountingModule {
+            call_count: std::sync::Mutex::new(0),
+        };
+
+        assert!(module.try_providers(&Injector::root()).is_ok());
+
+        #[cfg(not(feature = "thread-safe"))]
+        assert_eq!(*module.call_count.borrow(), 1);
+        #[cfg(feature = "thread-safe")]
+        assert_eq!(*module.call_count.lock().unwrap(), 1);
+    }
 }
@@ -0,0 +1,261 @@
+//! Shared construction state for singletons being built.
+//!
+//! The first task that resolves an uncached async singleton runs the factory;
+//! every other task awaits the same [`Pending`] entry until that construction
+//! stores the instance, fails, or is dropped. Only `std::task` primitives are
+//! used, so waiting works on any executor. In thread-safe mode, synchronous
+//! resolutions share the same entries and block their thread instead.
+//!
+//! Blocked threads are recorded in a wait-for graph shared by every injector:
+//! a thread about to wait for a construction that, through the threads
+//! waiting on each other, depends on one its own resolution holds, fails with
+//! a circular dependency instead of deadlocking.
+
+use std::future::Future;
+use std::pin::Pin;
+#[cfg(feature = "thread-safe")]
+use std::sync::{Mutex, PoisonError};
+use std::task::{Context, Poll, Waker};
+#[cfg(feature = "thread-safe")]
+use std::time::{Duration, Instant};
+
+use crate::error::Error;
+use crate::runtime::{Shared, Store};
+
+/// An in-flight async construction that other resolutions can wait on.
+pub(crate) struct Pending {
+    state: Store<PendingState>,
+}
+
+#[derive(Default)]
+struct PendingState {
+    /// `None` while the factory runs. `Some(Ok(()))` when waiters should look
+    /// the instance up again, `Some(Err(_))` when the factory failed.
+    outcome: Option<Result<(), Error>>,
+    wakers: Vec<Waker>,
+}
+
+impl Pending {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Store::new(PendingState::default()),
+        }
+    }
+
+    /// Records the outcome of the construction and wakes every waiter.
+    pub(crate) fn complete(&self, outcome: Result<(), Error>) {
+        #[cfg(not(feature = "thread-safe"))]
+        let wakers = {
+            let mut state = self.state.borrow_mut();
+            state.outcome = Some(outcome);
+            std::mem::take(&mut state.wakers)
+        };
+        #[cfg(feature = "thread-safe")]
+        let wakers = {
+            let mut state = self.state.write().unwrap();
+            state.outcome = Some(outcome);
+            std::mem::take(&mut state.wakers)
+        };
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Returns a future that resolves once [`complete`](Pending::complete) is called.
+    pub(crate) fn wait(self: Shared<Self>) -> Wait {
+        Wait { pending: self }
+    }
+}
+
+#[cfg(feature = "thread-safe")]
+impl Pending {
+    /// Blocks the current thread until [`complete`](Pending::complete) is
+    /// called, or until `timeout` elapses.
+    ///
+    /// `path` is the resolution path of the calling thread, ending with the
+    /// type this construction builds, and `held` the constructions claimed
+    /// along it with their position in `path`.
+    pub(crate) fn wait_blocking(
+        self: &Shared<Self>,
+        path: Vec<String>,
+        held: Vec<(usize, Shared<Pending>)>,
+        timeout: Option<Duration>,
+    ) -> Result<(), Error> {
+        let thread = std::thread::current();
+        let deadline = timeout.map(|timeout| (Instant::now() + timeout, timeout));
+
+        {
+            let mut waiters = WAITERS.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Some((closing, cycle)) = find_cycle(&waiters, self, &held, &mut Vec::new()) {
+                return Err(circular_wait(&thread, &path, closing, &cycle));
+            }
+            waiters.push(Waiter {
+                thread: thread.clone(),
+                path: path.clone(),
+                held,
+                awaited: self.clone(),
+            });
+        }
+
+        let waker = Waker::from(Shared::new(ThreadWaker(thread.clone())));
+        let outcome = loop {
+            {
+                let mut state = self.state.write().unwrap();
+                if let Some(outcome) = &state.outcome {
+                    break outcome.clone();
+                }
+                if !state.wakers.iter().any(|other| other.will_wake(&waker)) {
+                    state.wakers.push(waker.clone());
+                }
+            }
+
+            match deadline {
+                Some((deadline, timeout)) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        let type_name = path.last().map(String::as_str).unwrap_or_default();
+                        break Err(Error::init_timeout(type_name, timeout));
+                    }
+                    std::thread::park_timeout(deadline - now);
+                }
+                None => std::thread::park(),
+            }
+        };
+
+        WAITERS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|waiter| waiter.thread.id() != thread.id());
+
+        outcome
+    }
+}
+
+/// Threads blocked in [`Pending::wait_blocking`]: the edges of the wait-for
+/// graph between constructions in flight.
+#[cfg(feature = "thread-safe")]
+static WAITERS: Mutex<Vec<Waiter>> = Mutex::new(Vec::new());
+
+#[cfg(feature = "thread-safe")]
+struct Waiter {
+    thread: std::thread::Thread,
+    /// Resolution path of the thread, ending with the awaited type.
+    path: Vec<String>,
+    /// Constructions claimed along `path`, with their position in it.
+    held: Vec<(usize, Shared<Pending>)>,
+    awaited: Shared<Pending>,
+}
+
+/// Looks for threads that, starting from the one building `awaited`, each
+/// wait for a construction held by the next and end up waiting for one of
+/// `held`.
+///
+/// Returns the position in the caller's path of the construction closing the
+/// cycle, and each waiter of the cycle with the position in its own path of
+/// the construction the previous one waits for.
+#[cfg(feature = "thread-safe")]
+fn find_cycle<'a>(
+    waiters: &'a [Waiter],
+    awaited: &Shared<Pending>,
+    held: &[(usize, Shared<Pending>)],
+    visited: &mut Vec<Shared<Pending>>,
+) -> Option<(usize, Vec<(&'a Waiter, usize)>)> {
+    for waiter in waiters {
+        let Some(&(position, _)) = waiter
+            .held
+            .iter()
+            .find(|(_, pending)| Shared::ptr_eq(pending, awaited))
+        else {
+            continue;
+        };
+
+        if let Some(&(closing, _)) = held
+            .iter()
+            .find(|(_, pending)| Shared::ptr_eq(pending, &waiter.awaited))
+        {
+            return Some((closing, vec![(waiter, position)]));
+        }
+
+        if visited
+            .iter()
+            .any(|pending| Shared::ptr_eq(pending, &waiter.awaited))
+        {
+            continue;
+        }
+        visited.push(waiter.awaited.clone());
+
+        if let Some((closing, mut cycle)) = find_cycle(waiters, &waiter.awaited, held, visited) {
+            cycle.insert(0, (waiter, position));
+            return Some((closing, cycle));
+        }
+    }
+
+    None
+}
+
+/// Builds the error for a wait by `thread` that would close `cycle`.
+#[cfg(feature = "thread-safe")]
+fn circular_wait(
+    thread: &std::thread::Thread,
+    path: &[String],
+    closing: usize,
+    cycle: &[(&Waiter, usize)],
+) -> Error {
+    let mut types: Vec<&str> = path[closing..].iter().map(String::as_str).collect();
+    let mut threads = vec![describe_wait(thread, path)];
+
+    for (waiter, position) in cycle {
+        types.extend(waiter.path[position + 1..].iter().map(String::as_str));
+        threads.push(describe_wait(&waiter.thread, &waiter.path));
+    }
+
+    Error::circular_wait(&types, &threads)
+}
+
+#[cfg(feature = "thread-safe")]
+fn describe_wait(thread: &std::thread::Thread, path: &[String]) -> String {
+    let name = match thread.name() {
+        Some(name) => format!("'{}'", name),
+        None => format!("{:?}", thread.id()),
+    };
+
+    format!("thread {} resolving {}", name, path.join(" -> "))
+}
+
+/// Wakes a thread blocked in [`Pending::wait_blocking`].
+#[cfg(feature = "thread-safe")]
+struct ThreadWaker(std::thread::Thread);
+
+#[cfg(feature = "thread-safe")]
+impl std::task::Wake for ThreadWaker {
+    fn wake(self: Shared<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Future returned by [`Pending::wait`].
+pub(crate) struct Wait {
+    pending: Shared<Pending>,
+}
+
+impl Future for Wait {
+    type Output = Result<(), Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        #[cfg(not(feature = "thread-safe"))]
+        let mut state = self.pending.state.borrow_mut();
+        #[cfg(feature = "thread-safe")]
+        let mut state = self.pending.state.write().unwrap();
+
+        if let Some(outcome) = &state.outcome {
+            return Poll::Ready(outcome.clone());
+        }
+
+        if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+
+        Poll::Pending
+    }
+}
@@ -9,6 +9,27 @@
 //! - **Transient**: New instance on every resolution
 //! - **Root**: One instance per root injector (application-wide)
 //!
+//! # Fallible Factories
+//!
+//! Every scope also has a `try_` constructor ([`Provider::try_singleton`],
+//! [`Provider::try_transient`], [`Provider::try_root`]) whose factory returns a
+//! `Result`. A failing factory surfaces from [`Injector::try_resolve`] as an
+//! [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) error
+//! that keeps the original error as its source. Nothing is cached on failure,
+//! so a later resolution runs the factory again.
+//!
+//! A factory can use `?` on [`Injector::try_resolve`] to pass a failed
+//! dependency up instead of panicking in [`Injector::resolve`]. Such errors are
+//! returned unchanged, keeping their kind and resolution path.
+//!
+//! # Async Factories
+//!
+//! The `_async` constructors ([`Provider::singleton_async`],
+//! [`Provider::transient_async`], [`Provider::root_async`] and their `try_`
+//! counterparts) take a factory that returns a future. Such providers are
+//! resolved with [`Injector::try_resolve_async`]; concurrent first-time
+//! resolutions of a singleton await a single construction.
+//!
 //! # Thread Safety
 //!
 //! The module supports two compilation modes via the `thread-safe` feature flag:
@@ -47,19 +68,38 @@
 //! });
 //! ```
 
+use crate::dispose::{AsyncDispose, Dispose, DisposeError, DisposeHook};
+use crate::error::Error;
 use crate::injector::Injector;
 use crate::instance::Instance;
-use crate::runtime::Shared;
+use crate::runtime::{BoxFuture, Shared};
 use crate::scope::Scope;
 
+use std::future::Future;
+
 #[cfg(feature = "tracing")]
 use tracing::{debug, info};
 
+/// Boxed async factory stored by providers built with the `_async` constructors.
+///
+/// The factory receives an owned [`Injector`] handle so the returned future can
+/// keep resolving dependencies across `.await` points.
+#[cfg(not(feature = "thread-safe"))]
+pub type AsyncFactory<T> =
+    Box<dyn Fn(Injector) -> BoxFuture<'static, Result<Instance<T>, Error>> + 'static>;
+
+/// Boxed async factory stored by providers built with the `_async` constructors
+/// (thread-safe variant).
+#[cfg(feature = "thread-safe")]
+pub type AsyncFactory<T> =
+    Box<dyn Fn(Injector) -> BoxFuture<'static, Result<Instance<T>, Error>> + Send + Sync + 'static>;
+
 /// A provider encapsulates the factory logic for creating instances of type `T`.
 ///
 /// The provider stores:
-/// - The lifecycle [`Scope`] (singleton, transient, or root)
-/// - A factory function that creates [`Instance<T>`] when invoked
+/// - The lifecycle [`Scope`] (singleton, transient, root, or scoped)
+/// - A factory function that creates [`Instance<T>`] when invoked, or reports
+///   why it could not
 ///
 /// # Type Parameters
 ///
@@ -97,12 +137,26 @@ pub struct Provider<T: ?Sized + 'static> {
     /// In thread-safe mode, the factory must also be `Send + Sync`.
     #[allow(clippy::type_complexity)]
     #[cfg(not(feature = "thread-safe"))]
-    pub factory: Box<dyn Fn(&Injector) -> Instance<T> + 'static>,
+    pub factory: Box<dyn Fn(&Injector) -> Result<Instance<T>, Error> + 'static>,
 
     /// The factory function that creates instances (thread-safe variant)
     #[allow(clippy::type_complexity)]
     #[cfg(feature = "thread-safe")]
-    pub factory: Box<dyn Fn(&Injector) -> Instance<T> + Send + Sync + 'static>,
+    pub factory: Box<dyn Fn(&Injector) -> Result<Instance<T>, Error> + Send + Sync + 'static>,
+
+    /// The async factory, set by the `_async` constructors
+    ///
+    /// When present, the synchronous `factory` only reports
+    /// [`ErrorKind::AsyncFactory`](crate::error::ErrorKind::AsyncFactory).
+    pub async_factory: Option<AsyncFactory<T>>,
+
+    /// Teardown hook set by [`with_dispose`](Provider::with_dispose) or
+    /// [`with_async_dispose`](Provider::with_async_dispose)
+    pub dispose: Option<DisposeHook<T>>,
+
+    /// Whether bootstrap instantiates the singleton, set by
+    /// [`eager`](Provider::eager)
+    pub eager: bool,
 }
 
 #[cfg(feature = "debug")]
@@ -116,7 +170,7 @@ impl<T: ?Sized + 'static> std::fmt::Debug for Provider<T> {
         {
             ds.field(
                 "factory",
-                &"Box<dyn Fn(&Injector) -> Instance<T> + Send + Sync + 'static>",
+                &"Box<dyn Fn(&Injector) -> Result<Instance<T>, Error> + Send + Sync + 'static>",
             );
         }
 
@@ -124,14 +178,111 @@ impl<T: ?Sized + 'static> std::fmt::Debug for Provider<T> {
         {
             ds.field(
                 "factory",
-                &"Box<dyn Fn(&Injector) -> Instance<T> + 'static>",
+                &"Box<dyn Fn(&Injector) -> Result<Instance<T>, Error> + 'static>",
             );
         }
 
+        ds.field("async_factory", &self.async_factory.is_some());
+        ds.field("dispose", &self.dispose.is_some());
+        ds.field("eager", &self.eager);
+
         ds.finish()
     }
 }
 
+impl<T: ?Sized + 'static> Provider<T> {
+    /// Instantiates the singleton during bootstrap instead of on first use.
+    ///
+    /// [`Application::try_bootstrap`](crate::application::Application::try_bootstrap)
+    /// resolves eager providers once every module is loaded, module by module
+    /// in load order and before the [`on_bootstrap`](crate::module::Module::on_bootstrap)
+    /// hooks, so a misconfigured service fails the bootstrap. Dependencies are
+    /// resolved first, as usual, and the instance is cached exactly as if it
+    /// had been resolved normally.
+    ///
+    /// Only Root and Module singletons are instantiated eagerly. The flag is
+    /// ignored on Transient providers, which build a new instance on every
+    /// resolution, and on Scoped ones, which have no scope to be cached in
+    /// during bootstrap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct DbClient;
+    ///
+    /// let provider = Provider::root(|_| Shared::new(DbClient)).eager();
+    /// assert!(provider.eager);
+    /// ```
+    pub fn eager(mut self) -> Self {
+        self.eager = true;
+        self
+    }
+
+    /// Disposes the singletons built by this provider through [`Dispose`].
+    ///
+    /// The hook runs when the injector caching the instance is disposed, see
+    /// [`Injector::dispose`](crate::injector::Injector::dispose). Transient
+    /// instances are not disposed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Dispose, DisposeError, Provider, Shared};
+    ///
+    /// struct Pool;
+    ///
+    /// impl Dispose for Pool {
+    ///     fn dispose(&self) -> Result<(), DisposeError> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let provider = Provider::root(|_| Shared::new(Pool)).with_dispose();
+    /// assert!(provider.dispose.is_some());
+    /// ```
+    pub fn with_dispose(mut self) -> Self
+    where
+        T: Dispose,
+    {
+        self.dispose = Some(DisposeHook::Sync(|value| value.dispose()));
+        self
+    }
+
+    /// Disposes the singletons built by this provider through [`AsyncDispose`].
+    ///
+    /// Such instances are disposed by
+    /// [`Injector::dispose_async`](crate::injector::Injector::dispose_async);
+    /// the synchronous [`Injector::dispose`](crate::injector::Injector::dispose)
+    /// reports them as errors instead.
+    #[cfg(not(feature = "thread-safe"))]
+    pub fn with_async_dispose(mut self) -> Self
+    where
+        T: AsyncDispose,
+    {
+        self.dispose = Some(DisposeHook::Async(
+            |value| -> BoxFuture<'static, Result<(), DisposeError>> {
+                Box::pin(async move { value.dispose_async().await })
+            },
+        ));
+        self
+    }
+
+    #[cfg(feature = "thread-safe")]
+    pub fn with_async_dispose(mut self) -> Self
+    where
+        T: AsyncDispose + Send + Sync,
+    {
+        self.dispose = Some(DisposeHook::Async(
+            |value| -> BoxFuture<'static, Result<(), DisposeError>> {
+                Box::pin(async move { value.dispose_async().await })
+            },
+        ));
+        self
+    }
+}
+
 #[cfg(not(feature = "thread-safe"))]
 impl<T: ?Sized + 'static> Provider<T> {
     /// Creates a singleton provider with module scope (single-threaded).
@@ -174,12 +325,15 @@ impl<T: ?Sized + 'static> Provider<T> {
 
         Provider::<T> {
             scope: Scope::Module,
+            dispose: None,
+            eager: false,
             factory: Box::new(move |injector| {
                 #[cfg(feature = "tracing")]
                 debug!("Executing singleton factory for type instantiation");
 
-                Instance::new(factory(injector))
+                Ok(Instance::new(factory(injector)))
             }),
+            async_factory: None,
         }
     }
 
@@ -207,295 +361,1420 @@ impl<T: ?Sized + 'static> Provider<T> {
     /// ```
     /// use sadi::{Provider, Shared};
     ///
-    /// struct RequestHandler {
-    ///     id: u64,
-    /// }
+    /// struct RequestHandler {
+    ///     id: u64,
+    /// }
+    ///
+    /// let provider = Provider::transient(|_injector| {
+    ///     Shared::new(RequestHandler {
+    ///         id: std::time::SystemTime::now()
+    ///             .duration_since(std::time::UNIX_EPOCH)
+    ///             .unwrap()
+    ///             .as_nanos() as u64,
+    ///     })
+    /// });
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is the single-threaded version. The factory does not need to be `Send + Sync`.
+    pub fn transient<F>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Shared<T> + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating transient provider with Transient scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Transient,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing transient factory - creating new instance");
+
+                Ok(Instance::new(factory(injector)))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a root-scoped provider (single-threaded).
+    ///
+    /// A root provider creates **one instance per root injector** (application-wide).
+    /// This is the highest level of singleton, shared across all child injectors.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Shared<T>`
+    ///
+    /// # Arguments
+    ///
+    /// - `factory`: A closure that creates the instance when first requested
+    ///
+    /// # Use Cases
+    ///
+    /// - Application configuration
+    /// - Logging infrastructure
+    /// - Connection pools
+    /// - Global caches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct AppConfig {
+    ///     version: String,
+    /// }
+    ///
+    /// let provider = Provider::root(|_injector| {
+    ///     Shared::new(AppConfig {
+    ///         version: "1.0.0".to_string(),
+    ///     })
+    /// });
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is the single-threaded version. The factory does not need to be `Send + Sync`.
+    pub fn root<F>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Shared<T> + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating root provider with Root scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Root,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing root factory for type instantiation");
+
+                Ok(Instance::new(factory(injector)))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a fallible singleton provider with module scope (not thread-safe).
+    ///
+    /// Behaves like [`Provider::singleton`], but the factory returns a `Result`.
+    /// An `Err` is reported by [`Injector::try_resolve`] as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) with the
+    /// original error as its source, and nothing is cached, so the next
+    /// resolution runs the factory again.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Result<Shared<T>, E>`
+    /// - `E`: Any error convertible into `Box<dyn std::error::Error + Send + Sync>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{ErrorKind, Injector, Provider, Shared};
+    ///
+    /// struct Database;
+    ///
+    /// let injector = Injector::root();
+    /// injector.provide::<Database>(Provider::try_singleton(|_injector| {
+    ///     Err::<Shared<Database>, _>("connection refused")
+    /// }));
+    ///
+    /// let err = injector.try_resolve::<Database>().err().unwrap();
+    /// assert!(err.kind == ErrorKind::FactoryFailed);
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is the single-threaded version. The factory does not need to be `Send + Sync`.
+    pub fn try_singleton<F, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Result<Shared<T>, E> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible singleton provider with Module scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Module,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible singleton factory for type instantiation");
+
+                factory(injector)
+                    .map(Instance::new)
+                    .map_err(|error| Error::from_factory(std::any::type_name::<T>(), error.into()))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a fallible transient provider (not thread-safe).
+    ///
+    /// Behaves like [`Provider::transient`], but the factory returns a `Result`.
+    /// An `Err` is reported by [`Injector::try_resolve`] as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Port(u16);
+    ///
+    /// let provider = Provider::try_transient(|_injector| {
+    ///     "8080".parse::<u16>().map(|port| Shared::new(Port(port)))
+    /// });
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is the single-threaded version. The factory does not need to be `Send + Sync`.
+    pub fn try_transient<F, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Result<Shared<T>, E> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible transient provider with Transient scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Transient,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible transient factory - creating new instance");
+
+                factory(injector)
+                    .map(Instance::new)
+                    .map_err(|error| Error::from_factory(std::any::type_name::<T>(), error.into()))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a fallible root-scoped provider (not thread-safe).
+    ///
+    /// Behaves like [`Provider::root`], but the factory returns a `Result`.
+    /// An `Err` is reported by [`Injector::try_resolve`] as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// the failed instance is not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct AppConfig {
+    ///     raw: String,
+    /// }
+    ///
+    /// let provider = Provider::try_root(|_injector| {
+    ///     std::env::var("APP_CONFIG").map(|raw| Shared::new(AppConfig { raw }))
+    /// });
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is the single-threaded version. The factory does not need to be `Send + Sync`.
+    pub fn try_root<F, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Result<Shared<T>, E> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible root provider with Root scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Root,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible root factory for type instantiation");
+
+                factory(injector)
+                    .map(Instance::new)
+                    .map_err(|error| Error::from_factory(std::any::type_name::<T>(), error.into()))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a singleton provider with an async factory (not thread-safe).
+    ///
+    /// Same lifecycle as [`Provider::singleton`], but the factory returns a future.
+    /// The provider must be resolved with [`Injector::try_resolve_async`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Database;
+    ///
+    /// let provider = Provider::singleton_async(|_injector| async { Shared::new(Database) });
+    /// ```
+    pub fn singleton_async<F, Fut>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + 'static,
+        Fut: Future<Output = Shared<T>> + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating async singleton provider with Module scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Module,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing async singleton factory");
+
+                let future = factory(injector);
+                Box::pin(async move { Ok(Instance::new(future.await)) })
+            })),
+        }
+    }
+
+    /// Creates a transient provider with an async factory (not thread-safe).
+    ///
+    /// Same lifecycle as [`Provider::transient`], but the factory returns a future.
+    /// The provider must be resolved with [`Injector::try_resolve_async`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Connection;
+    ///
+    /// let provider = Provider::transient_async(|_injector| async { Shared::new(Connection) });
+    /// ```
+    pub fn transient_async<F, Fut>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + 'static,
+        Fut: Future<Output = Shared<T>> + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating async transient provider with Transient scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Transient,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing async transient factory");
+
+                let future = factory(injector);
+                Box::pin(async move { Ok(Instance::new(future.await)) })
+            })),
+        }
+    }
+
+    /// Creates a root-scoped provider with an async factory (not thread-safe).
+    ///
+    /// Same lifecycle as [`Provider::root`], but the factory returns a future.
+    /// The provider must be resolved with [`Injector::try_resolve_async`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Pool;
+    ///
+    /// let provider = Provider::root_async(|_injector| async { Shared::new(Pool) });
+    /// ```
+    pub fn root_async<F, Fut>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + 'static,
+        Fut: Future<Output = Shared<T>> + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating async root provider with Root scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Root,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing async root factory");
+
+                let future = factory(injector);
+                Box::pin(async move { Ok(Instance::new(future.await)) })
+            })),
+        }
+    }
+
+    /// Creates a fallible provider with an async factory and module scope (not thread-safe).
+    ///
+    /// Combines [`Provider::try_singleton`] and [`Provider::singleton_async`]: an `Err`
+    /// from the future is reported as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Database;
+    ///
+    /// let provider = Provider::try_singleton_async(|_injector| async {
+    ///     Ok::<_, std::io::Error>(Shared::new(Database))
+    /// });
+    /// ```
+    pub fn try_singleton_async<F, Fut, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + 'static,
+        Fut: Future<Output = Result<Shared<T>, E>> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible async singleton provider with Module scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Module,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible async singleton factory");
+
+                let future = factory(injector);
+                Box::pin(async move {
+                    future.await.map(Instance::new).map_err(|error| {
+                        Error::from_factory(std::any::type_name::<T>(), error.into())
+                    })
+                })
+            })),
+        }
+    }
+
+    /// Creates a fallible provider with an async factory and transient scope (not thread-safe).
+    ///
+    /// Combines [`Provider::try_transient`] and [`Provider::transient_async`]: an `Err`
+    /// from the future is reported as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Connection;
+    ///
+    /// let provider = Provider::try_transient_async(|_injector| async {
+    ///     Ok::<_, std::io::Error>(Shared::new(Connection))
+    /// });
+    /// ```
+    pub fn try_transient_async<F, Fut, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + 'static,
+        Fut: Future<Output = Result<Shared<T>, E>> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible async transient provider with Transient scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Transient,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible async transient factory");
+
+                let future = factory(injector);
+                Box::pin(async move {
+                    future.await.map(Instance::new).map_err(|error| {
+                        Error::from_factory(std::any::type_name::<T>(), error.into())
+                    })
+                })
+            })),
+        }
+    }
+
+    /// Creates a fallible provider with an async factory and root scope (not thread-safe).
+    ///
+    /// Combines [`Provider::try_root`] and [`Provider::root_async`]: an `Err`
+    /// from the future is reported as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Pool;
+    ///
+    /// let provider = Provider::try_root_async(|_injector| async {
+    ///     Ok::<_, std::io::Error>(Shared::new(Pool))
+    /// });
+    /// ```
+    pub fn try_root_async<F, Fut, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + 'static,
+        Fut: Future<Output = Result<Shared<T>, E>> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible async root provider with Root scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Root,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible async root factory");
+
+                let future = factory(injector);
+                Box::pin(async move {
+                    future.await.map(Instance::new).map_err(|error| {
+                        Error::from_factory(std::any::type_name::<T>(), error.into())
+                    })
+                })
+            })),
+        }
+    }
+    /// Creates a scoped provider (single-threaded).
+    ///
+    /// A scoped provider creates **one instance per scope** opened with
+    /// [`Injector::create_scope`]. The instance is cached in the scope and
+    /// disposed when the scope is dropped. Resolving it outside of a scope
+    /// fails with [`ErrorKind::NoActiveScope`](crate::error::ErrorKind::NoActiveScope).
+    ///
+    /// # Use Cases
+    ///
+    /// - Database transactions
+    /// - Request-bound loggers and contexts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Injector, Provider, Shared};
+    ///
+    /// struct RequestId(u64);
+    ///
+    /// let injector = Injector::root();
+    /// injector.provide::<RequestId>(Provider::scoped(|_injector| Shared::new(RequestId(7))));
+    ///
+    /// let request = injector.create_scope();
+    /// assert!(Shared::ptr_eq(
+    ///     &request.resolve::<RequestId>(),
+    ///     &request.resolve::<RequestId>()
+    /// ));
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This is the single-threaded version. The factory does not need to be `Send + Sync`.
+    pub fn scoped<F>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Shared<T> + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating scoped provider with Scoped scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Scoped,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing scoped factory for type instantiation");
+
+                Ok(Instance::new(factory(injector)))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a fallible scoped provider (not thread-safe).
+    ///
+    /// Behaves like [`Provider::scoped`], but the factory returns a `Result`.
+    /// An `Err` is reported by [`Injector::try_resolve`] as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached in the scope.
+    ///
+    /// # Note
+    ///
+    /// This is the single-threaded version. The factory does not need to be `Send + Sync`.
+    pub fn try_scoped<F, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Result<Shared<T>, E> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible scoped provider with Scoped scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Scoped,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible scoped factory for type instantiation");
+
+                factory(injector)
+                    .map(Instance::new)
+                    .map_err(|error| Error::from_factory(std::any::type_name::<T>(), error.into()))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a scoped provider with an async factory (not thread-safe).
+    ///
+    /// Same lifecycle as [`Provider::scoped`], but the factory returns a future.
+    /// The provider must be resolved with [`Injector::try_resolve_async`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Transaction;
+    ///
+    /// let provider = Provider::scoped_async(|_injector| async { Shared::new(Transaction) });
+    /// ```
+    pub fn scoped_async<F, Fut>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + 'static,
+        Fut: Future<Output = Shared<T>> + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating async scoped provider with Scoped scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Scoped,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing async scoped factory");
+
+                let future = factory(injector);
+                Box::pin(async move { Ok(Instance::new(future.await)) })
+            })),
+        }
+    }
+
+    /// Creates a fallible provider with an async factory and scoped lifecycle (not thread-safe).
+    ///
+    /// Combines [`Provider::try_scoped`] and [`Provider::scoped_async`]: an `Err`
+    /// from the future is reported as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached.
+    pub fn try_scoped_async<F, Fut, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + 'static,
+        Fut: Future<Output = Result<Shared<T>, E>> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible async scoped provider with Scoped scope (not thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Scoped,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible async scoped factory");
+
+                let future = factory(injector);
+                Box::pin(async move {
+                    future.await.map(Instance::new).map_err(|error| {
+                        Error::from_factory(std::any::type_name::<T>(), error.into())
+                    })
+                })
+            })),
+        }
+    }
+}
+
+#[cfg(feature = "thread-safe")]
+impl<T: ?Sized + 'static> Provider<T> {
+    /// Creates a singleton provider with module scope (thread-safe).
+    ///
+    /// A singleton provider creates **one instance per injector module**.
+    /// Once created, the same instance is returned on subsequent resolutions
+    /// within the same module. The instance can be safely shared across threads.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Shared<T>`.
+    ///   Must be `Send + Sync` for thread safety.
+    ///
+    /// # Arguments
+    ///
+    /// - `factory`: A closure that creates the instance when first requested.
+    ///   The closure must be `Send + Sync`.
+    ///
+    /// # Thread Safety
+    ///
+    /// The factory function must be `Send + Sync` because it may be called
+    /// from any thread. The returned `Shared<T>` (which is `Arc<T>` in thread-safe mode)
+    /// ensures safe concurrent access to the instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// #[derive(Debug)]
+    /// struct Database {
+    ///     connection_count: std::sync::atomic::AtomicUsize,
+    /// }
+    ///
+    /// let provider = Provider::singleton(|_injector| {
+    ///     Shared::new(Database {
+    ///         connection_count: std::sync::atomic::AtomicUsize::new(0),
+    ///     })
+    /// });
+    /// ```
+    ///
+    /// With trait objects:
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    /// use std::sync::Arc;
+    ///
+    /// trait Cache: Send + Sync {}
+    /// struct MemoryCache;
+    /// impl Cache for MemoryCache {}
+    ///
+    /// let provider = Provider::<dyn Cache>::singleton(|_injector| {
+    ///     Arc::new(MemoryCache) as Arc<dyn Cache>
+    /// });
+    /// ```
+    pub fn singleton<F>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Shared<T> + Send + Sync + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating singleton provider with Module scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Module,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing singleton factory for type instantiation");
+
+                Ok(Instance::new(factory(injector)))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a transient provider (thread-safe).
+    ///
+    /// A transient provider creates a **new instance on every resolution**.
+    /// No caching or instance reuse occurs. Each instance can be safely used
+    /// across threads.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Shared<T>`.
+    ///   Must be `Send + Sync` for thread safety.
+    ///
+    /// # Arguments
+    ///
+    /// - `factory`: A closure that creates a new instance on each invocation.
+    ///   The closure must be `Send + Sync`.
+    ///
+    /// # Use Cases
+    ///
+    /// - Per-request services in web applications
+    /// - Task-specific handlers
+    /// - Stateful operations that should not be shared
+    ///
+    /// # Thread Safety
+    ///
+    /// While each resolution creates a new instance, the factory itself must
+    /// be thread-safe (`Send + Sync`) as it may be called from multiple threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    ///
+    /// static COUNTER: AtomicU64 = AtomicU64::new(0);
+    ///
+    /// struct RequestHandler {
+    ///     id: u64,
+    /// }
+    ///
+    /// let provider = Provider::transient(|_injector| {
+    ///     Shared::new(RequestHandler {
+    ///         id: COUNTER.fetch_add(1, Ordering::SeqCst),
+    ///     })
+    /// });
+    /// ```
+    pub fn transient<F>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Shared<T> + Send + Sync + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating transient provider with Transient scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Transient,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing transient factory - creating new instance");
+
+                Ok(Instance::new(factory(injector)))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a root-scoped provider (thread-safe).
+    ///
+    /// A root provider creates **one instance per root injector** (application-wide).
+    /// This is the highest level of singleton, shared across all child injectors
+    /// and safe to access from any thread.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Shared<T>`.
+    ///   Must be `Send + Sync` for thread safety.
+    ///
+    /// # Arguments
+    ///
+    /// - `factory`: A closure that creates the instance when first requested.
+    ///   The closure must be `Send + Sync`.
+    ///
+    /// # Use Cases
+    ///
+    /// - Application-wide configuration
+    /// - Logging infrastructure
+    /// - Thread-safe connection pools
+    /// - Global metrics collectors
+    /// - Shared caches
+    ///
+    /// # Thread Safety
+    ///
+    /// The root-scoped instance is shared across all threads and modules.
+    /// Both the factory and the instance must be thread-safe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    /// use std::sync::RwLock;
+    ///
+    /// struct GlobalConfig {
+    ///     settings: RwLock<std::collections::HashMap<String, String>>,
+    /// }
+    ///
+    /// let provider = Provider::root(|_injector| {
+    ///     Shared::new(GlobalConfig {
+    ///         settings: RwLock::new(std::collections::HashMap::new()),
+    ///     })
+    /// });
+    /// ```
+    pub fn root<F>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Shared<T> + Send + Sync + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating root provider with Root scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Root,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing root factory for type instantiation");
+
+                Ok(Instance::new(factory(injector)))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a fallible singleton provider with module scope (thread-safe).
+    ///
+    /// Behaves like [`Provider::singleton`], but the factory returns a `Result`.
+    /// An `Err` is reported by [`Injector::try_resolve`] as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) with the
+    /// original error as its source, and nothing is cached, so the next
+    /// resolution runs the factory again.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Result<Shared<T>, E>`
+    /// - `E`: Any error convertible into `Box<dyn std::error::Error + Send + Sync>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{ErrorKind, Injector, Provider, Shared};
+    ///
+    /// struct Database;
+    ///
+    /// let injector = Injector::root();
+    /// injector.provide::<Database>(Provider::try_singleton(|_injector| {
+    ///     Err::<Shared<Database>, _>("connection refused")
+    /// }));
+    ///
+    /// let err = injector.try_resolve::<Database>().err().unwrap();
+    /// assert!(err.kind == ErrorKind::FactoryFailed);
+    /// ```
+    ///
+    /// # Thread Safety
+    ///
+    /// The factory must be `Send + Sync`, like the one given to [`Provider::singleton`].
+    pub fn try_singleton<F, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Result<Shared<T>, E> + Send + Sync + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible singleton provider with Module scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Module,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible singleton factory for type instantiation");
+
+                factory(injector)
+                    .map(Instance::new)
+                    .map_err(|error| Error::from_factory(std::any::type_name::<T>(), error.into()))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a fallible transient provider (thread-safe).
+    ///
+    /// Behaves like [`Provider::transient`], but the factory returns a `Result`.
+    /// An `Err` is reported by [`Injector::try_resolve`] as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Port(u16);
+    ///
+    /// let provider = Provider::try_transient(|_injector| {
+    ///     "8080".parse::<u16>().map(|port| Shared::new(Port(port)))
+    /// });
+    /// ```
+    ///
+    /// # Thread Safety
+    ///
+    /// The factory must be `Send + Sync`, like the one given to [`Provider::transient`].
+    pub fn try_transient<F, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Result<Shared<T>, E> + Send + Sync + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible transient provider with Transient scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Transient,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible transient factory - creating new instance");
+
+                factory(injector)
+                    .map(Instance::new)
+                    .map_err(|error| Error::from_factory(std::any::type_name::<T>(), error.into()))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a fallible root-scoped provider (thread-safe).
+    ///
+    /// Behaves like [`Provider::root`], but the factory returns a `Result`.
+    /// An `Err` is reported by [`Injector::try_resolve`] as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// the failed instance is not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct AppConfig {
+    ///     raw: String,
+    /// }
+    ///
+    /// let provider = Provider::try_root(|_injector| {
+    ///     std::env::var("APP_CONFIG").map(|raw| Shared::new(AppConfig { raw }))
+    /// });
+    /// ```
+    ///
+    /// # Thread Safety
+    ///
+    /// The factory must be `Send + Sync`, like the one given to [`Provider::root`].
+    pub fn try_root<F, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Result<Shared<T>, E> + Send + Sync + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible root provider with Root scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Root,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible root factory for type instantiation");
+
+                factory(injector)
+                    .map(Instance::new)
+                    .map_err(|error| Error::from_factory(std::any::type_name::<T>(), error.into()))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a singleton provider with an async factory (thread-safe).
+    ///
+    /// Same lifecycle as [`Provider::singleton`], but the factory returns a future.
+    /// The provider must be resolved with [`Injector::try_resolve_async`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Database;
+    ///
+    /// let provider = Provider::singleton_async(|_injector| async { Shared::new(Database) });
+    /// ```
+    pub fn singleton_async<F, Fut>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Shared<T>> + Send + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating async singleton provider with Module scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Module,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing async singleton factory");
+
+                let future = factory(injector);
+                Box::pin(async move { Ok(Instance::new(future.await)) })
+            })),
+        }
+    }
+
+    /// Creates a transient provider with an async factory (thread-safe).
+    ///
+    /// Same lifecycle as [`Provider::transient`], but the factory returns a future.
+    /// The provider must be resolved with [`Injector::try_resolve_async`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::{Provider, Shared};
+    ///
+    /// struct Connection;
     ///
-    /// let provider = Provider::transient(|_injector| {
-    ///     Shared::new(RequestHandler {
-    ///         id: std::time::SystemTime::now()
-    ///             .duration_since(std::time::UNIX_EPOCH)
-    ///             .unwrap()
-    ///             .as_nanos() as u64,
-    ///     })
-    /// });
+    /// let provider = Provider::transient_async(|_injector| async { Shared::new(Connection) });
     /// ```
-    ///
-    /// # Note
-    ///
-    /// This is the single-threaded version. The factory does not need to be `Send + Sync`.
-    pub fn transient<F>(factory: F) -> Provider<T>
+    pub fn transient_async<F, Fut>(factory: F) -> Provider<T>
     where
-        F: Fn(&Injector) -> Shared<T> + 'static,
+        F: Fn(Injector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Shared<T>> + Send + 'static,
     {
         #[cfg(feature = "tracing")]
-        info!("Creating transient provider with Transient scope (not thread-safe)");
+        info!("Creating async transient provider with Transient scope (thread-safe)");
 
         Provider::<T> {
             scope: Scope::Transient,
-            factory: Box::new(move |injector| {
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
                 #[cfg(feature = "tracing")]
-                debug!("Executing transient factory - creating new instance");
+                debug!("Executing async transient factory");
 
-                Instance::new(factory(injector))
-            }),
+                let future = factory(injector);
+                Box::pin(async move { Ok(Instance::new(future.await)) })
+            })),
         }
     }
 
-    /// Creates a root-scoped provider (single-threaded).
-    ///
-    /// A root provider creates **one instance per root injector** (application-wide).
-    /// This is the highest level of singleton, shared across all child injectors.
-    ///
-    /// # Type Parameters
-    ///
-    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Shared<T>`
-    ///
-    /// # Arguments
-    ///
-    /// - `factory`: A closure that creates the instance when first requested
-    ///
-    /// # Use Cases
+    /// Creates a root-scoped provider with an async factory (thread-safe).
     ///
-    /// - Application configuration
-    /// - Logging infrastructure
-    /// - Connection pools
-    /// - Global caches
+    /// Same lifecycle as [`Provider::root`], but the factory returns a future.
+    /// The provider must be resolved with [`Injector::try_resolve_async`].
     ///
     /// # Examples
     ///
     /// ```
     /// use sadi::{Provider, Shared};
     ///
-    /// struct AppConfig {
-    ///     version: String,
-    /// }
+    /// struct Pool;
     ///
-    /// let provider = Provider::root(|_injector| {
-    ///     Shared::new(AppConfig {
-    ///         version: "1.0.0".to_string(),
-    ///     })
-    /// });
+    /// let provider = Provider::root_async(|_injector| async { Shared::new(Pool) });
     /// ```
-    ///
-    /// # Note
-    ///
-    /// This is the single-threaded version. The factory does not need to be `Send + Sync`.
-    pub fn root<F>(factory: F) -> Provider<T>
+    pub fn root_async<F, Fut>(factory: F) -> Provider<T>
     where
-        F: Fn(&Injector) -> Shared<T> + 'static,
+        F: Fn(Injector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Shared<T>> + Send + 'static,
     {
         #[cfg(feature = "tracing")]
-        info!("Creating root provider with Root scope (not thread-safe)");
+        info!("Creating async root provider with Root scope (thread-safe)");
 
         Provider::<T> {
             scope: Scope::Root,
-            factory: Box::new(move |injector| {
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
                 #[cfg(feature = "tracing")]
-                debug!("Executing root factory for type instantiation");
+                debug!("Executing async root factory");
 
-                Instance::new(factory(injector))
-            }),
+                let future = factory(injector);
+                Box::pin(async move { Ok(Instance::new(future.await)) })
+            })),
         }
     }
-}
 
-#[cfg(feature = "thread-safe")]
-impl<T: ?Sized + 'static> Provider<T> {
-    /// Creates a singleton provider with module scope (thread-safe).
-    ///
-    /// A singleton provider creates **one instance per injector module**.
-    /// Once created, the same instance is returned on subsequent resolutions
-    /// within the same module. The instance can be safely shared across threads.
-    ///
-    /// # Type Parameters
-    ///
-    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Shared<T>`.
-    ///   Must be `Send + Sync` for thread safety.
-    ///
-    /// # Arguments
+    /// Creates a fallible provider with an async factory and module scope (thread-safe).
     ///
-    /// - `factory`: A closure that creates the instance when first requested.
-    ///   The closure must be `Send + Sync`.
-    ///
-    /// # Thread Safety
-    ///
-    /// The factory function must be `Send + Sync` because it may be called
-    /// from any thread. The returned `Shared<T>` (which is `Arc<T>` in thread-safe mode)
-    /// ensures safe concurrent access to the instance.
+    /// Combines [`Provider::try_singleton`] and [`Provider::singleton_async`]: an `Err`
+    /// from the future is reported as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached.
     ///
     /// # Examples
     ///
     /// ```
     /// use sadi::{Provider, Shared};
     ///
-    /// #[derive(Debug)]
-    /// struct Database {
-    ///     connection_count: std::sync::atomic::AtomicUsize,
-    /// }
+    /// struct Database;
     ///
-    /// let provider = Provider::singleton(|_injector| {
-    ///     Shared::new(Database {
-    ///         connection_count: std::sync::atomic::AtomicUsize::new(0),
-    ///     })
+    /// let provider = Provider::try_singleton_async(|_injector| async {
+    ///     Ok::<_, std::io::Error>(Shared::new(Database))
     /// });
     /// ```
+    pub fn try_singleton_async<F, Fut, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Shared<T>, E>> + Send + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible async singleton provider with Module scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Module,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible async singleton factory");
+
+                let future = factory(injector);
+                Box::pin(async move {
+                    future.await.map(Instance::new).map_err(|error| {
+                        Error::from_factory(std::any::type_name::<T>(), error.into())
+                    })
+                })
+            })),
+        }
+    }
+
+    /// Creates a fallible provider with an async factory and transient scope (thread-safe).
     ///
-    /// With trait objects:
+    /// Combines [`Provider::try_transient`] and [`Provider::transient_async`]: an `Err`
+    /// from the future is reported as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use sadi::{Provider, Shared};
-    /// use std::sync::Arc;
     ///
-    /// trait Cache: Send + Sync {}
-    /// struct MemoryCache;
-    /// impl Cache for MemoryCache {}
+    /// struct Connection;
     ///
-    /// let provider = Provider::<dyn Cache>::singleton(|_injector| {
-    ///     Arc::new(MemoryCache) as Arc<dyn Cache>
+    /// let provider = Provider::try_transient_async(|_injector| async {
+    ///     Ok::<_, std::io::Error>(Shared::new(Connection))
     /// });
     /// ```
-    pub fn singleton<F>(factory: F) -> Provider<T>
+    pub fn try_transient_async<F, Fut, E>(factory: F) -> Provider<T>
     where
-        F: Fn(&Injector) -> Shared<T> + Send + Sync + 'static,
+        F: Fn(Injector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Shared<T>, E>> + Send + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
     {
         #[cfg(feature = "tracing")]
-        info!("Creating singleton provider with Module scope (thread-safe)");
+        info!("Creating fallible async transient provider with Transient scope (thread-safe)");
 
         Provider::<T> {
-            scope: Scope::Module,
-            factory: Box::new(move |injector| {
+            scope: Scope::Transient,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
                 #[cfg(feature = "tracing")]
-                debug!("Executing singleton factory for type instantiation");
+                debug!("Executing fallible async transient factory");
 
-                Instance::new(factory(injector))
-            }),
+                let future = factory(injector);
+                Box::pin(async move {
+                    future.await.map(Instance::new).map_err(|error| {
+                        Error::from_factory(std::any::type_name::<T>(), error.into())
+                    })
+                })
+            })),
         }
     }
 
-    /// Creates a transient provider (thread-safe).
-    ///
-    /// A transient provider creates a **new instance on every resolution**.
-    /// No caching or instance reuse occurs. Each instance can be safely used
-    /// across threads.
+    /// Creates a fallible provider with an async factory and root scope (thread-safe).
     ///
-    /// # Type Parameters
+    /// Combines [`Provider::try_root`] and [`Provider::root_async`]: an `Err`
+    /// from the future is reported as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached.
     ///
-    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Shared<T>`.
-    ///   Must be `Send + Sync` for thread safety.
+    /// # Examples
     ///
-    /// # Arguments
+    /// ```
+    /// use sadi::{Provider, Shared};
     ///
-    /// - `factory`: A closure that creates a new instance on each invocation.
-    ///   The closure must be `Send + Sync`.
+    /// struct Pool;
     ///
-    /// # Use Cases
+    /// let provider = Provider::try_root_async(|_injector| async {
+    ///     Ok::<_, std::io::Error>(Shared::new(Pool))
+    /// });
+    /// ```
+    pub fn try_root_async<F, Fut, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Shared<T>, E>> + Send + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible async root provider with Root scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Root,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible async root factory");
+
+                let future = factory(injector);
+                Box::pin(async move {
+                    future.await.map(Instance::new).map_err(|error| {
+                        Error::from_factory(std::any::type_name::<T>(), error.into())
+                    })
+                })
+            })),
+        }
+    }
+    /// Creates a scoped provider (thread-safe).
     ///
-    /// - Per-request services in web applications
-    /// - Task-specific handlers
-    /// - Stateful operations that should not be shared
+    /// A scoped provider creates **one instance per scope** opened with
+    /// [`Injector::create_scope`]. The instance is cached in the scope and
+    /// disposed when the scope is dropped. Resolving it outside of a scope
+    /// fails with [`ErrorKind::NoActiveScope`](crate::error::ErrorKind::NoActiveScope).
     ///
-    /// # Thread Safety
+    /// # Use Cases
     ///
-    /// While each resolution creates a new instance, the factory itself must
-    /// be thread-safe (`Send + Sync`) as it may be called from multiple threads.
+    /// - Database transactions
+    /// - Request-bound loggers and contexts
     ///
     /// # Examples
     ///
     /// ```
-    /// use sadi::{Provider, Shared};
-    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use sadi::{Injector, Provider, Shared};
     ///
-    /// static COUNTER: AtomicU64 = AtomicU64::new(0);
+    /// struct RequestId(u64);
     ///
-    /// struct RequestHandler {
-    ///     id: u64,
-    /// }
+    /// let injector = Injector::root();
+    /// injector.provide::<RequestId>(Provider::scoped(|_injector| Shared::new(RequestId(7))));
     ///
-    /// let provider = Provider::transient(|_injector| {
-    ///     Shared::new(RequestHandler {
-    ///         id: COUNTER.fetch_add(1, Ordering::SeqCst),
-    ///     })
-    /// });
+    /// let request = injector.create_scope();
+    /// assert!(Shared::ptr_eq(
+    ///     &request.resolve::<RequestId>(),
+    ///     &request.resolve::<RequestId>()
+    /// ));
     /// ```
-    pub fn transient<F>(factory: F) -> Provider<T>
+    ///
+    /// # Note
+    ///
+    /// This is the thread-safe version. The factory must be `Send + Sync`.
+    pub fn scoped<F>(factory: F) -> Provider<T>
     where
         F: Fn(&Injector) -> Shared<T> + Send + Sync + 'static,
     {
         #[cfg(feature = "tracing")]
-        info!("Creating transient provider with Transient scope (thread-safe)");
+        info!("Creating scoped provider with Scoped scope (thread-safe)");
 
         Provider::<T> {
-            scope: Scope::Transient,
+            scope: Scope::Scoped,
+            dispose: None,
+            eager: false,
             factory: Box::new(move |injector| {
                 #[cfg(feature = "tracing")]
-                debug!("Executing transient factory - creating new instance");
+                debug!("Executing scoped factory for type instantiation");
 
-                Instance::new(factory(injector))
+                Ok(Instance::new(factory(injector)))
             }),
+            async_factory: None,
         }
     }
 
-    /// Creates a root-scoped provider (thread-safe).
-    ///
-    /// A root provider creates **one instance per root injector** (application-wide).
-    /// This is the highest level of singleton, shared across all child injectors
-    /// and safe to access from any thread.
+    /// Creates a fallible scoped provider (thread-safe).
     ///
-    /// # Type Parameters
-    ///
-    /// - `F`: Factory function type that takes an [`Injector`] reference and returns `Shared<T>`.
-    ///   Must be `Send + Sync` for thread safety.
-    ///
-    /// # Arguments
-    ///
-    /// - `factory`: A closure that creates the instance when first requested.
-    ///   The closure must be `Send + Sync`.
-    ///
-    /// # Use Cases
+    /// Behaves like [`Provider::scoped`], but the factory returns a `Result`.
+    /// An `Err` is reported by [`Injector::try_resolve`] as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached in the scope.
     ///
-    /// - Application-wide configuration
-    /// - Logging infrastructure
-    /// - Thread-safe connection pools
-    /// - Global metrics collectors
-    /// - Shared caches
+    /// # Note
     ///
-    /// # Thread Safety
+    /// This is the thread-safe version. The factory must be `Send + Sync`.
+    pub fn try_scoped<F, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(&Injector) -> Result<Shared<T>, E> + Send + Sync + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible scoped provider with Scoped scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Scoped,
+            dispose: None,
+            eager: false,
+            factory: Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible scoped factory for type instantiation");
+
+                factory(injector)
+                    .map(Instance::new)
+                    .map_err(|error| Error::from_factory(std::any::type_name::<T>(), error.into()))
+            }),
+            async_factory: None,
+        }
+    }
+
+    /// Creates a scoped provider with an async factory (thread-safe).
     ///
-    /// The root-scoped instance is shared across all threads and modules.
-    /// Both the factory and the instance must be thread-safe.
+    /// Same lifecycle as [`Provider::scoped`], but the factory returns a future.
+    /// The provider must be resolved with [`Injector::try_resolve_async`].
     ///
     /// # Examples
     ///
     /// ```
     /// use sadi::{Provider, Shared};
-    /// use std::sync::RwLock;
     ///
-    /// struct GlobalConfig {
-    ///     settings: RwLock<std::collections::HashMap<String, String>>,
-    /// }
+    /// struct Transaction;
     ///
-    /// let provider = Provider::root(|_injector| {
-    ///     Shared::new(GlobalConfig {
-    ///         settings: RwLock::new(std::collections::HashMap::new()),
-    ///     })
-    /// });
+    /// let provider = Provider::scoped_async(|_injector| async { Shared::new(Transaction) });
     /// ```
-    pub fn root<F>(factory: F) -> Provider<T>
+    pub fn scoped_async<F, Fut>(factory: F) -> Provider<T>
     where
-        F: Fn(&Injector) -> Shared<T> + Send + Sync + 'static,
+        F: Fn(Injector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Shared<T>> + Send + 'static,
     {
         #[cfg(feature = "tracing")]
-        info!("Creating root provider with Root scope (thread-safe)");
+        info!("Creating async scoped provider with Scoped scope (thread-safe)");
 
         Provider::<T> {
-            scope: Scope::Root,
-            factory: Box::new(move |injector| {
+            scope: Scope::Scoped,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
                 #[cfg(feature = "tracing")]
-                debug!("Executing root factory for type instantiation");
+                debug!("Executing async scoped factory");
 
-                Instance::new(factory(injector))
-            }),
+                let future = factory(injector);
+                Box::pin(async move { Ok(Instance::new(future.await)) })
+            })),
+        }
+    }
+
+    /// Creates a fallible provider with an async factory and scoped lifecycle (thread-safe).
+    ///
+    /// Combines [`Provider::try_scoped`] and [`Provider::scoped_async`]: an `Err`
+    /// from the future is reported as
+    /// [`ErrorKind::FactoryFailed`](crate::error::ErrorKind::FactoryFailed) and
+    /// nothing is cached.
+    pub fn try_scoped_async<F, Fut, E>(factory: F) -> Provider<T>
+    where
+        F: Fn(Injector) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Shared<T>, E>> + Send + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        #[cfg(feature = "tracing")]
+        info!("Creating fallible async scoped provider with Scoped scope (thread-safe)");
+
+        Provider::<T> {
+            scope: Scope::Scoped,
+            dispose: None,
+            eager: false,
+            factory: Box::new(|_| Err(Error::async_factory(std::any::type_name::<T>()))),
+            async_factory: Some(Box::new(move |injector| {
+                #[cfg(feature = "tracing")]
+                debug!("Executing fallible async scoped factory");
+
+                let future = factory(injector);
+                Box::pin(async move {
+                    future.await.map(Instance::new).map_err(|error| {
+                        Error::from_factory(std::any::type_name::<T>(), error.into())
+                    })
+                })
+            })),
         }
     }
 }
@@ -582,7 +1861,7 @@ mod tests {
         });
 
         let injector = Injector::root();
-        let instance = (provider.factory)(&injector);
+        let instance = (provider.factory)(&injector).unwrap();
         let value = instance.get();
 
         assert_eq!(value.id, 42);
@@ -604,8 +1883,8 @@ mod tests {
 
         let injector = Injector::root();
 
-        let instance1 = (provider.factory)(&injector);
-        let instance2 = (provider.factory)(&injector);
+        let instance1 = (provider.factory)(&injector).unwrap();
+        let instance2 = (provider.factory)(&injector).unwrap();
 
         // Each call to factory creates new instance (counter increments)
         assert_eq!(instance1.get().id, 0);
@@ -621,7 +1900,7 @@ mod tests {
         });
 
         let injector = Injector::root();
-        let instance = (provider.factory)(&injector);
+        let instance = (provider.factory)(&injector).unwrap();
 
         // Just verify it compiles and runs
         let _repo = instance.get();
@@ -654,9 +1933,9 @@ mod tests {
 
         let injector = Injector::root();
 
-        let instance1 = (provider.factory)(&injector);
-        let instance2 = (provider.factory)(&injector);
-        let instance3 = (provider.factory)(&injector);
+        let instance1 = (provider.factory)(&injector).unwrap();
+        let instance2 = (provider.factory)(&injector).unwrap();
+        let instance3 = (provider.factory)(&injector).unwrap();
 
         // Each call creates a new instance with incremented ID
         assert_eq!(instance1.get().id, 0);
@@ -677,8 +1956,8 @@ mod tests {
         });
 
         let injector = Injector::root();
-        let _instance1 = (provider.factory)(&injector);
-        let _instance2 = (provider.factory)(&injector);
+        let _instance1 = (provider.factory)(&injector).unwrap();
+        let _instance2 = (provider.factory)(&injector).unwrap();
 
         // Verify counter was incremented twice
         assert_eq!(counter.increment(), 2);
@@ -706,7 +1985,7 @@ mod tests {
         });
 
         let injector = Injector::root();
-        let instance = (provider.factory)(&injector);
+        let instance = (provider.factory)(&injector).unwrap();
         let value = instance.get();
 
         assert_eq!(value.id, 100);
@@ -725,8 +2004,8 @@ mod tests {
         let injector1 = Injector::root();
         let injector2 = Injector::root();
 
-        let instance1 = (provider.factory)(&injector1);
-        let instance2 = (provider.factory)(&injector2);
+        let instance1 = (provider.factory)(&injector1).unwrap();
+        let instance2 = (provider.factory)(&injector2).unwrap();
 
         // Both instances have the same configuration
         assert_eq!(instance1.get().name, "global-config");
@@ -778,7 +2057,7 @@ mod tests {
         });
 
         let injector = Injector::root();
-        let instance = (provider.factory)(&injector);
+        let instance = (provider.factory)(&injector).unwrap();
 
         assert_eq!(instance.get().name, "test-42");
     }
@@ -797,7 +2076,7 @@ mod tests {
         });
 
         let injector = Injector::root();
-        let instance = (provider.factory)(&injector);
+        let instance = (provider.factory)(&injector).unwrap();
 
         assert_eq!(instance.get().id, 999);
     }
@@ -812,7 +2091,7 @@ mod tests {
         });
 
         let injector = Injector::root();
-        let instance = (provider.factory)(&injector);
+        let instance = (provider.factory)(&injector).unwrap();
 
         let value1 = instance.get();
         let value2 = instance.get();
@@ -832,7 +2111,7 @@ mod tests {
         });
 
         let injector = Injector::root();
-        let instance = (provider.factory)(&injector);
+        let instance = (provider.factory)(&injector).unwrap();
 
         let shared1 = instance.value();
         let shared2 = instance.value();
@@ -859,7 +2138,7 @@ mod tests {
         });
 
         let injector = Injector::root();
-        let instance = (provider.factory)(&injector);
+        let instance = (provider.factory)(&injector).unwrap();
 
         assert_eq!(instance.get().id, 101);
         assert_eq!(instance.get().name, "depends-on-1");
@@ -884,14 +2163,52 @@ mod tests {
             Provider::<dyn Logger>::transient(|_| Shared::new(FileLogger) as Shared<dyn Logger>);
 
         let injector = Injector::root();
-        let _console = (console_provider.factory)(&injector);
-        let _file = (file_provider.factory)(&injector);
+        let _console = (console_provider.factory)(&injector).unwrap();
+        let _file = (file_provider.factory)(&injector).unwrap();
 
         // Just verify both work with different scopes
         assert_eq!(console_provider.scope, Scope::Module);
         assert_eq!(file_provider.scope, Scope::Transient);
     }
 
+    #[test]
+    fn test_try_providers_keep_their_scope() {
+        let singleton = Provider::<TestService>::try_singleton(|_| Err("unused"));
+        let transient = Provider::<TestService>::try_transient(|_| Err("unused"));
+        let root = Provider::<TestService>::try_root(|_| Err("unused"));
+
+        assert_eq!(singleton.scope, Scope::Module);
+        assert_eq!(transient.scope, Scope::Transient);
+        assert_eq!(root.scope, Scope::Root);
+    }
+
+    #[test]
+    fn test_try_provider_creates_instance() {
+        let provider = Provider::try_singleton(|_| {
+            Ok::<_, std::fmt::Error>(Shared::new(TestService {
+                id: 7,
+                name: "fallible".to_string(),
+            }))
+        });
+
+        let injector = Injector::root();
+        let instance = (provider.factory)(&injector).unwrap();
+
+        assert_eq!(instance.get().id, 7);
+    }
+
+    #[test]
+    fn test_try_provider_wraps_error() {
+        let provider = Provider::<TestService>::try_root(|_| Err("missing config"));
+
+        let injector = Injector::root();
+        let err = (provider.factory)(&injector).err().unwrap();
+
+        assert!(err.kind == crate::error::ErrorKind::FactoryFailed);
+        assert!(err.message.contains("TestService"));
+        assert_eq!(err.source.unwrap().to_string(), "missing config");
+    }
+
     #[cfg(feature = "debug")]
     #[test]
     fn test_provider_debug_format() {
@@ -936,7 +2253,7 @@ mod tests {
                 let provider_clone = Arc::clone(&provider);
                 thread::spawn(move || {
                     let injector = Injector::root();
-                    let instance = (provider_clone.factory)(&injector);
+                    let instance = (provider_clone.factory)(&injector).unwrap();
                     instance.get().id
                 })
             })
@@ -970,7 +2287,7 @@ mod tests {
                 let provider_clone = Arc::clone(&provider);
                 thread::spawn(move || {
                     let injector = Injector::root();
-                    let instance = (provider_clone.factory)(&injector);
+                    let instance = (provider_clone.factory)(&injector).unwrap();
                     instance.get().id
                 })
             })
@@ -1,50 +1,180 @@
-use std::{any::TypeId, cell::RefCell};
+//! Tracking of the types under construction during a resolution.
+//!
+//! Each resolution carries its own `ResolveChain` through the injector
+//! handed to factories, rather than through state shared by the thread: a
+//! nested resolve continues the chain of the handle it is made on, whichever
+//! thread or task runs it, and handles of unrelated injectors start their own.
 
-use crate::error::{Error, ErrorKind};
+use crate::error::Error;
+use crate::injector::Key;
+#[cfg(feature = "thread-safe")]
+use crate::pending::Pending;
+use crate::runtime::Shared;
+use crate::scope::Scope;
 
-thread_local! {
-    static RESOLVE_STACK: RefCell<Vec<TypeId>> = const { RefCell::new(Vec::new()) };
+/// Builds the error for `key`, of the given `scope`, resolved while `captor`
+/// is built.
+pub(crate) fn captive_dependency(captor: &(Key, Scope), key: &Key, scope: Scope) -> Error {
+    let (dependent, dependent_scope) = captor;
+    Error::captive_dependency(
+        &dependent.describe(),
+        &dependent_scope.to_string(),
+        &key.describe(),
+        &scope.to_string(),
+    )
 }
 
-pub struct ResolveGuard {
-    type_id: TypeId,
+/// Builds the error for a cycle, given the keys from the first occurrence of
+/// `key` up to the one currently being resolved.
+fn circular_dependency(cycle: &[Key], key: &Key) -> Error {
+    let names: Vec<String> = cycle
+        .iter()
+        .chain(std::iter::once(key))
+        .map(Key::describe)
+        .collect();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+
+    Error::circular_dependency(&names)
 }
 
-impl ResolveGuard {
-    pub fn push(type_id: TypeId) -> Result<Self, Error> {
-        RESOLVE_STACK.with(|stack| {
-            let mut stack = stack.borrow_mut();
-
-            if stack.contains(&type_id) {
-                return Err(Error::new(
-                    ErrorKind::CircularDependency,
-                    format!(
-                        "Circular dependency detected while resolving type_id: {:?}",
-                        type_id
-                    ),
-                ));
-            }
+/// Types under construction in one resolution, innermost first, with the
+/// scope of their provider once it is known.
+///
+/// Used to detect circular and captive dependencies and to report the path
+/// of a failed resolution.
+#[derive(Clone, Default)]
+pub(crate) struct ResolveChain {
+    head: Option<Shared<ChainLink>>,
+}
 
-            stack.push(type_id);
-            Ok(Self { type_id })
-        })
-    }
+struct ChainLink {
+    key: Key,
+    scope: Option<Scope>,
+    /// Construction of `key` claimed by this resolution, which other threads
+    /// may be waiting for.
+    #[cfg(feature = "thread-safe")]
+    pending: Option<Shared<Pending>>,
+    parent: Option<Shared<ChainLink>>,
 }
 
-impl Drop for ResolveGuard {
-    fn drop(&mut self) {
-        RESOLVE_STACK.with(|stack| {
-            let mut stack = stack.borrow_mut();
-            if let Some(last) = stack.pop() {
-                if last != self.type_id {
-                    panic!(
-                        "ResolveGuard stack corrupted: expected to pop {:?} but popped {:?}",
-                        self.type_id, last
-                    );
-                }
-            } else {
-                panic!("ResolveGuard stack corrupted: attempted to pop from an empty stack");
+impl ResolveChain {
+    /// Fails when `key` is already under construction in this chain.
+    pub(crate) fn check(&self, key: &Key) -> Result<(), Error> {
+        let mut cycle = Vec::new();
+        let mut current = self.head.as_deref();
+
+        while let Some(link) = current {
+            cycle.push(link.key.clone());
+            if link.key == *key {
+                cycle.reverse();
+                return Err(circular_dependency(&cycle, key));
             }
+            current = link.parent.as_deref();
+        }
+
+        Ok(())
+    }
+
+    /// This chain with `key` under construction, once it is known not to
+    /// close a cycle.
+    pub(crate) fn push(&self, key: &Key) -> Result<Self, Error> {
+        self.check(key)?;
+        Ok(self.link(key, None))
+    }
+
+    /// This chain with `key`, built by a provider of the given `scope`,
+    /// under construction.
+    ///
+    /// Unlike [`push`](ResolveChain::push), no cycle is looked for: callers
+    /// [`check`](ResolveChain::check) the key first, and only link it when a
+    /// factory is about to run, so that cached resolutions allocate nothing.
+    pub(crate) fn enter(&self, key: &Key, scope: Scope) -> Self {
+        self.link(key, Some(scope))
+    }
+
+    fn link(&self, key: &Key, scope: Option<Scope>) -> Self {
+        Self {
+            head: Some(Shared::new(ChainLink {
+                key: key.clone(),
+                scope,
+                #[cfg(feature = "thread-safe")]
+                pending: None,
+                parent: self.head.clone(),
+            })),
+        }
+    }
+
+    /// This chain with its innermost key being built under `pending`.
+    #[cfg(feature = "thread-safe")]
+    pub(crate) fn building(&self, pending: &Shared<Pending>) -> Self {
+        let head = self.head.as_deref().map(|link| {
+            Shared::new(ChainLink {
+                key: link.key.clone(),
+                scope: link.scope,
+                pending: Some(pending.clone()),
+                parent: link.parent.clone(),
+            })
         });
+
+        Self { head }
+    }
+
+    /// Constructions claimed along this chain, with their position in
+    /// [`path`](ResolveChain::path).
+    #[cfg(feature = "thread-safe")]
+    pub(crate) fn held(&self) -> Vec<(usize, Shared<Pending>)> {
+        let mut held = Vec::new();
+        let mut depth = 0;
+        let mut current = self.head.as_deref();
+
+        while let Some(link) = current {
+            if let Some(pending) = &link.pending {
+                held.push((depth, pending.clone()));
+            }
+            depth += 1;
+            current = link.parent.as_deref();
+        }
+
+        held.into_iter()
+            .map(|(depth_from_head, pending)| (depth - 1 - depth_from_head, pending))
+            .collect()
+    }
+
+    /// The innermost service under construction that outlives `scope`, and
+    /// would capture a dependency of that scope resolved now.
+    pub(crate) fn captor(&self, scope: Scope) -> Option<(Key, Scope)> {
+        let mut current = self.head.as_deref();
+
+        while let Some(link) = current {
+            if let Some(captor) = link.scope
+                && captor.outlives(scope)
+            {
+                return Some((link.key.clone(), captor));
+            }
+            current = link.parent.as_deref();
+        }
+
+        None
+    }
+
+    /// Types in this chain, outermost first.
+    pub(crate) fn path(&self) -> Vec<String> {
+        let mut path = Vec::new();
+        let mut current = self.head.as_deref();
+
+        while let Some(link) = current {
+            path.push(link.key.describe());
+            current = link.parent.as_deref();
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Types in this chain followed by `key`, the one being resolved on it.
+    pub(crate) fn path_to(&self, key: &Key) -> Vec<String> {
+        let mut path = self.path();
+        path.push(key.describe());
+        path
     }
 }
@@ -9,6 +9,7 @@
 //!
 //! - [`Shared<T>`]: Smart pointer for shared ownership
 //! - [`Store<T>`]: Container providing interior mutability
+//! - [`BoxFuture<'a, T>`]: Boxed future returned by async factories
 //!
 //! # Examples
 //!
@@ -20,6 +21,8 @@
 //! let shared = Shared::new(value);
 //! ```
 
+use std::{future::Future, pin::Pin};
+
 #[cfg(feature = "thread-safe")]
 use std::sync::{Arc, RwLock};
 
@@ -81,6 +84,24 @@ pub type Store<T> = RwLock<T>;
 #[cfg(not(feature = "thread-safe"))]
 pub type Store<T> = RefCell<T>;
 
+/// Type alias for a heap-allocated, type-erased future.
+///
+/// When the `thread-safe` feature is enabled, the future must be `Send` so that
+/// resolution can move between worker threads of a multi-threaded executor.
+/// When disabled, no `Send` bound is required.
+///
+/// # Examples
+///
+/// ```
+/// use sadi::runtime::BoxFuture;
+///
+/// let future: BoxFuture<'static, u32> = Box::pin(async { 42 });
+/// ```
+#[cfg(feature = "thread-safe")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+#[cfg(not(feature = "thread-safe"))]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
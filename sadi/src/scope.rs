@@ -6,14 +6,22 @@ use tracing::debug;
 /// # Variants
 ///
 /// * `Root` - Root-level singleton. The instance is created once and shared
-///   across the entire application during its complete lifecycle.
+///   across the entire application during its complete lifecycle. Its factory
+///   resolves dependencies from the root injector, whichever injector
+///   requested it first.
 ///
 /// * `Module` - Module-level singleton. The instance is created once per module
-///   and shared among all consumers within that specific module.
+///   and shared among all consumers within that specific module. It is cached
+///   in the injector where the provider is registered and reused by every
+///   descendant of that injector.
 ///
 /// * `Transient` - Transient instance. A new instance is created each time
 ///   the service is requested from the dependency injection container.
 ///
+/// * `Scoped` - Scoped instance. The instance is created once per scope opened
+///   with [`Injector::create_scope`](crate::injector::Injector::create_scope)
+///   and disposed when that scope is dropped.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -31,6 +39,7 @@ pub enum Scope {
     Root,
     Module,
     Transient,
+    Scoped,
 }
 
 impl std::fmt::Display for Scope {
@@ -39,6 +48,7 @@ impl std::fmt::Display for Scope {
             Scope::Root => write!(f, "Root"),
             Scope::Module => write!(f, "Module"),
             Scope::Transient => write!(f, "Transient"),
+            Scope::Scoped => write!(f, "Scoped"),
         }
     }
 }
@@ -48,12 +58,13 @@ impl Scope {
     ///
     /// Returns `true` for `Root` and `Module` scopes, which create a single shared
     /// instance. Returns `false` for `Transient` scope, which creates a new
-    /// instance on each request.
+    /// instance on each request, and for `Scoped` scope, which creates one
+    /// instance per scope.
     ///
     /// # Returns
     ///
     /// * `true` - If the scope is `Root` or `Module` (singleton)
-    /// * `false` - If the scope is `Transient` (not singleton)
+    /// * `false` - If the scope is `Transient` or `Scoped` (not singleton)
     ///
     /// # Examples
     ///
@@ -63,6 +74,7 @@ impl Scope {
     /// assert!(Scope::Root.is_singleton());
     /// assert!(Scope::Module.is_singleton());
     /// assert!(!Scope::Transient.is_singleton());
+    /// assert!(!Scope::Scoped.is_singleton());
     /// ```
     pub fn is_singleton(self) -> bool {
         let result = matches!(self, Scope::Root | Scope::Module);
@@ -75,6 +87,71 @@ impl Scope {
 
         result
     }
+
+    /// Checks if instances of this scope live longer than those of `other`.
+    ///
+    /// Scopes are ordered from the longest lived to the shortest: `Root`,
+    /// `Module`, `Scoped`, then `Transient`. A service that depends on one of
+    /// a shorter-lived scope keeps that dependency alive past its intended
+    /// lifetime, which is reported as a captive dependency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sadi::Scope;
+    ///
+    /// assert!(Scope::Root.outlives(Scope::Module));
+    /// assert!(Scope::Module.outlives(Scope::Transient));
+    /// assert!(!Scope::Scoped.outlives(Scope::Module));
+    /// assert!(!Scope::Root.outlives(Scope::Root));
+    /// ```
+    pub fn outlives(self, other: Scope) -> bool {
+        self.lifetime() > other.lifetime()
+    }
+
+    fn lifetime(self) -> u8 {
+        match self {
+            Scope::Transient => 0,
+            Scope::Scoped => 1,
+            Scope::Module => 2,
+            Scope::Root => 3,
+        }
+    }
+}
+
+/// How the container reacts to a captive dependency: a service depending on
+/// one with a shorter-lived [`Scope`], such as a Root singleton resolving a
+/// Transient service.
+///
+/// # Variants
+///
+/// * `Deny` - The resolution fails with
+///   [`ErrorKind::CaptiveDependency`](crate::error::ErrorKind::CaptiveDependency).
+///   This is the default.
+///
+/// * `Warn` - The resolution succeeds and, with the `tracing` feature, a
+///   warning naming both types is logged.
+///
+/// * `Allow` - The resolution succeeds silently.
+///
+/// # Examples
+///
+/// ```
+/// use sadi::{CaptivePolicy, Injector};
+///
+/// let injector = Injector::root();
+/// assert!(injector.captive_policy() == CaptivePolicy::Deny);
+///
+/// injector.set_captive_policy(CaptivePolicy::Warn);
+/// assert!(injector.captive_policy() == CaptivePolicy::Warn);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum CaptivePolicy {
+    #[default]
+    Deny,
+    Warn,
+    Allow,
 }
 
 #[cfg(test)]
@@ -102,6 +179,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scoped_is_not_singleton() {
+        let scope = Scope::Scoped;
+        assert!(
+            !scope.is_singleton(),
+            "Scope::Scoped should not be singleton"
+        );
+    }
+
     #[test]
     fn test_scope_is_copy() {
         let scope1 = Scope::Root;
@@ -119,10 +205,21 @@ mod tests {
         assert!(scope2.is_singleton());
     }
 
+    #[test]
+    fn test_scopes_are_ordered_by_lifetime() {
+        let scopes = [Scope::Root, Scope::Module, Scope::Scoped, Scope::Transient];
+
+        for (i, longer) in scopes.iter().enumerate() {
+            for (j, shorter) in scopes.iter().enumerate() {
+                assert_eq!(longer.outlives(*shorter), i < j);
+            }
+        }
+    }
+
     #[test]
     fn test_all_scopes_are_covered() {
         // Test that all enum variants have been considered
-        let scopes = [Scope::Root, Scope::Module, Scope::Transient];
+        let scopes = [Scope::Root, Scope::Module, Scope::Transient, Scope::Scoped];
 
         let singleton_count = scopes.iter().filter(|s| s.is_singleton()).count();
         assert_eq!(
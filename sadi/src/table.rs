@@ -0,0 +1,181 @@
+//! Keyed entries of an injector: providers, cached singletons and Scoped
+//! instances.
+//!
+//! By default a table is a `HashMap` behind a [`Store`]. With the `lock-free`
+//! feature it is a sharded concurrent map instead, so that lookups, which
+//! every resolution performs at each level of the injector chain, neither
+//! take nor wait on a lock shared by the whole table. Methods return owned
+//! entries and never hold a guard across calls, which keeps factories free to
+//! resolve from the same table.
+
+#[cfg(not(feature = "lock-free"))]
+use std::collections::HashMap;
+
+use crate::injector::{Entry, Key};
+#[cfg(not(feature = "lock-free"))]
+use crate::runtime::Store;
+
+pub(crate) struct Table {
+    #[cfg(not(feature = "lock-free"))]
+    entries: Store<HashMap<Key, Entry>>,
+    #[cfg(feature = "lock-free")]
+    entries: dashmap::DashMap<Key, Entry>,
+}
+
+#[cfg(feature = "debug")]
+impl std::fmt::Debug for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.keys()).finish()
+    }
+}
+
+impl Table {
+    pub(crate) fn new() -> Self {
+        Self {
+            #[cfg(not(feature = "lock-free"))]
+            entries: Store::new(HashMap::new()),
+            #[cfg(feature = "lock-free")]
+            entries: dashmap::DashMap::new(),
+        }
+    }
+
+    pub(crate) fn get(&self, key: &Key) -> Option<Entry> {
+        #[cfg(not(feature = "thread-safe"))]
+        let entry = self.entries.borrow().get(key).cloned();
+        #[cfg(all(feature = "thread-safe", not(feature = "lock-free")))]
+        let entry = self.entries.read().unwrap().get(key).cloned();
+        #[cfg(feature = "lock-free")]
+        let entry = self.entries.get(key).map(|entry| entry.value().clone());
+
+        entry
+    }
+
+    pub(crate) fn contains_key(&self, key: &Key) -> bool {
+        #[cfg(not(feature = "thread-safe"))]
+        let contains = self.entries.borrow().contains_key(key);
+        #[cfg(all(feature = "thread-safe", not(feature = "lock-free")))]
+        let contains = self.entries.read().unwrap().contains_key(key);
+        #[cfg(feature = "lock-free")]
+        let contains = self.entries.contains_key(key);
+
+        contains
+    }
+
+    /// Inserts `entry`, replacing any entry of `key`.
+    pub(crate) fn insert(&self, key: Key, entry: Entry) {
+        #[cfg(not(feature = "thread-safe"))]
+        self.entries.borrow_mut().insert(key, entry);
+        #[cfg(all(feature = "thread-safe", not(feature = "lock-free")))]
+        self.entries.write().unwrap().insert(key, entry);
+        #[cfg(feature = "lock-free")]
+        self.entries.insert(key, entry);
+    }
+
+    /// Inserts `entry` unless `key` already has one, returning whether it was
+    /// inserted.
+    pub(crate) fn insert_new(&self, key: Key, entry: Entry) -> bool {
+        #[cfg(not(feature = "lock-free"))]
+        {
+            use std::collections::hash_map::Entry as Slot;
+
+            #[cfg(not(feature = "thread-safe"))]
+            let mut entries = self.entries.borrow_mut();
+            #[cfg(feature = "thread-safe")]
+            let mut entries = self.entries.write().unwrap();
+
+            match entries.entry(key) {
+                Slot::Occupied(_) => false,
+                Slot::Vacant(slot) => {
+                    slot.insert(entry);
+                    true
+                }
+            }
+        }
+        #[cfg(feature = "lock-free")]
+        match self.entries.entry(key) {
+            dashmap::Entry::Occupied(_) => false,
+            dashmap::Entry::Vacant(slot) => {
+                slot.insert(entry);
+                true
+            }
+        }
+    }
+
+    /// Keys whose entry satisfies `filter`, in no particular order.
+    pub(crate) fn keys_where(&self, filter: impl Fn(&Entry) -> bool) -> Vec<Key> {
+        #[cfg(not(feature = "thread-safe"))]
+        let keys = self
+            .entries
+            .borrow()
+            .iter()
+            .filter(|(_, entry)| filter(entry))
+            .map(|(key, _)| key.clone())
+            .collect();
+        #[cfg(all(feature = "thread-safe", not(feature = "lock-free")))]
+        let keys = self
+            .entries
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, entry)| filter(entry))
+            .map(|(key, _)| key.clone())
+            .collect();
+        #[cfg(feature = "lock-free")]
+        let keys = self
+            .entries
+            .iter()
+            .filter(|item| filter(item.value()))
+            .map(|item| item.key().clone())
+            .collect();
+
+        keys
+    }
+
+    #[cfg(feature = "debug")]
+    fn keys(&self) -> Vec<Key> {
+        self.keys_where(|_| true)
+    }
+
+    pub(crate) fn clear(&self) {
+        #[cfg(not(feature = "thread-safe"))]
+        self.entries.borrow_mut().clear();
+        #[cfg(all(feature = "thread-safe", not(feature = "lock-free")))]
+        self.entries.write().unwrap().clear();
+        #[cfg(feature = "lock-free")]
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::Shared;
+
+    fn entry(value: u32) -> Entry {
+        Shared::new(value)
+    }
+
+    #[test]
+    fn test_insert_new_keeps_existing_entry() {
+        let table = Table::new();
+
+        assert!(table.insert_new(Key::of::<u32>(), entry(1)));
+        assert!(!table.insert_new(Key::of::<u32>(), entry(2)));
+
+        let stored = table.get(&Key::of::<u32>()).unwrap();
+        assert_eq!(stored.downcast_ref::<u32>(), Some(&1));
+    }
+
+    #[test]
+    fn test_named_keys_are_distinct_entries() {
+        let table = Table::new();
+        table.insert(Key::of::<u32>(), entry(1));
+        table.insert(Key::named::<u32>("port"), entry(2));
+
+        assert!(table.contains_key(&Key::named::<u32>("port")));
+        assert_eq!(table.keys_where(|entry| entry.is::<u32>()).len(), 2);
+
+        table.clear();
+        assert!(table.get(&Key::of::<u32>()).is_none());
+    }
+}